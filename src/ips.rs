@@ -1,8 +0,0 @@
-use serde::{Deserialize, Serialize};
-use std::net::{Ipv4Addr, Ipv6Addr};
-
-#[derive(Serialize, Deserialize, Debug, Default)]
-pub struct IPs {
-    pub ipv4: Option<Ipv4Addr>,
-    pub ipv6: Option<Ipv6Addr>,
-}
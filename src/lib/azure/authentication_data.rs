@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use super::PredefinedCloud;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    pub tenant_id: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub subscription_id: String,
+    pub resource_group: String,
+    pub cloud: Cloud,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Cloud {
+    PredefinedCloud(PredefinedCloud),
+    CustomCloud {
+        identity_url: url::Url,
+        management_url: url::Url,
+    },
+}
+
+impl Cloud {
+    pub fn identity_url(&self) -> url::Url {
+        match self {
+            Cloud::PredefinedCloud(cloud) => cloud.identity_url(),
+            Cloud::CustomCloud { identity_url, .. } => identity_url.clone(),
+        }
+    }
+
+    pub fn management_url(&self) -> url::Url {
+        match self {
+            Cloud::PredefinedCloud(cloud) => cloud.management_url(),
+            Cloud::CustomCloud { management_url, .. } => management_url.clone(),
+        }
+    }
+}
+
+impl From<PredefinedCloud> for Cloud {
+    fn from(predefined_cloud: PredefinedCloud) -> Self {
+        Cloud::PredefinedCloud(predefined_cloud)
+    }
+}
@@ -0,0 +1,113 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::record_set::{ARecord, AaaaRecord, RecordSet, RecordSetProperties};
+use super::token_source::TokenSource;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_VERSION: &str = "2018-05-01";
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    token_source: TokenSource,
+    resource_group_url: String,
+}
+
+impl Handler {
+    async fn put_record_set(
+        &self,
+        zone: &str,
+        name: &str,
+        record_type: RecordType,
+        record_set: &RecordSet,
+    ) -> Result<(), Error> {
+        let access_token = self.token_source.access_token().await?;
+        let response = self
+            .http_client
+            .put(format!(
+                "{}/providers/Microsoft.Network/dnsZones/{zone}/{record_type}/{name}",
+                self.resource_group_url
+            ))
+            .query(&[("api-version", API_VERSION)])
+            .bearer_auth(access_token)
+            .json(record_set)
+            .send()
+            .await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let text = response.text().await?;
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        auth_data: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+        let management_url = auth_data.cloud.management_url();
+
+        Ok(Handler {
+            token_source: TokenSource::new(
+                client.clone(),
+                &auth_data.cloud.identity_url(),
+                &auth_data.tenant_id,
+                &auth_data.client_id,
+                &auth_data.client_secret,
+                &management_url,
+            ),
+            http_client: client,
+            resource_group_url: format!(
+                "{}/subscriptions/{}/resourceGroups/{}",
+                management_url.as_str().trim_end_matches('/'),
+                auth_data.subscription_id,
+                auth_data.resource_group
+            ),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        let record_set = RecordSet {
+            properties: RecordSetProperties {
+                ttl: specification.ttl,
+                a_records: Some(vec![ARecord { ipv4_address: ip }]),
+                aaaa_records: None,
+            },
+        };
+        self.put_record_set(domain, host, RecordType::A, &record_set)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        let record_set = RecordSet {
+            properties: RecordSetProperties {
+                ttl: specification.record_specification.ttl,
+                a_records: None,
+                aaaa_records: Some(vec![AaaaRecord { ipv6_address: ip }]),
+            },
+        };
+        self.put_record_set(domain, host, RecordType::AAAA, &record_set)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+mod authentication_data;
+mod error;
+mod handler;
+mod predefined_cloud;
+mod record_set;
+mod record_specification;
+mod token_source;
+
+pub use authentication_data::{AuthenticationData, Cloud};
+pub use error::Error;
+pub use handler::Handler;
+pub use predefined_cloud::PredefinedCloud;
+pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("Azure"));
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+impl PredefinedCloud {
+    pub fn identity_url(&self) -> url::Url {
+        let str = match self {
+            PredefinedCloud::AzureCloud => "https://login.microsoftonline.com",
+            PredefinedCloud::AzureChinaCloud => "https://login.chinacloudapi.cn",
+            PredefinedCloud::AzureUSGovernment => "https://login.microsoftonline.us",
+        };
+
+        url::Url::parse(str).unwrap()
+    }
+
+    pub fn management_url(&self) -> url::Url {
+        let str = match self {
+            PredefinedCloud::AzureCloud => "https://management.azure.com",
+            PredefinedCloud::AzureChinaCloud => "https://management.chinacloudapi.cn",
+            PredefinedCloud::AzureUSGovernment => "https://management.usgovcloudapi.net",
+        };
+
+        url::Url::parse(str).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum PredefinedCloud {
+    AzureCloud,
+    AzureChinaCloud,
+    AzureUSGovernment,
+}
@@ -0,0 +1,30 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use serde::Serialize;
+
+#[derive(Serialize, Debug)]
+pub struct RecordSet {
+    pub properties: RecordSetProperties,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecordSetProperties {
+    #[serde(rename = "TTL")]
+    pub ttl: u32,
+    #[serde(rename = "ARecords", skip_serializing_if = "Option::is_none")]
+    pub a_records: Option<Vec<ARecord>>,
+    #[serde(rename = "AAAARecords", skip_serializing_if = "Option::is_none")]
+    pub aaaa_records: Option<Vec<AaaaRecord>>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ARecord {
+    pub ipv4_address: Ipv4Addr,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AaaaRecord {
+    pub ipv6_address: Ipv6Addr,
+}
@@ -0,0 +1,64 @@
+use crate::token_cache::{TokenCache, TokenResponse};
+
+use super::Error;
+
+/// Fetches access tokens for the management API via the OAuth2 client credentials flow.
+pub struct TokenSource {
+    http_client: reqwest::Client,
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: String,
+    token_cache: TokenCache,
+}
+
+impl TokenSource {
+    pub fn new(
+        http_client: reqwest::Client,
+        identity_url: &url::Url,
+        tenant_id: &str,
+        client_id: &str,
+        client_secret: &str,
+        management_url: &url::Url,
+    ) -> TokenSource {
+        TokenSource {
+            http_client,
+            token_url: format!(
+                "{}/{tenant_id}/oauth2/v2.0/token",
+                identity_url.as_str().trim_end_matches('/')
+            ),
+            client_id: client_id.to_owned(),
+            client_secret: client_secret.to_owned(),
+            scope: format!("{}/.default", management_url.as_str().trim_end_matches('/')),
+            token_cache: TokenCache::default(),
+        }
+    }
+
+    pub async fn access_token(&self) -> Result<String, Error> {
+        self.token_cache
+            .get_or_request(self.request_access_token())
+            .await
+    }
+
+    async fn request_access_token(&self) -> Result<TokenResponse, Error> {
+        let response = self
+            .http_client
+            .post(&self.token_url)
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("scope", &self.scope),
+            ])
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(Error::Request(format!(
+                "Token request failed with Code {status:?}: {text}"
+            )));
+        }
+        Ok(serde_json::from_str(&text)?)
+    }
+}
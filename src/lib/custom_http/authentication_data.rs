@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+/// Empty, as the credentials are given with the requests they belong to in the records, e.g.
+/// `"CustomHttp": {}`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {}
@@ -0,0 +1,34 @@
+pub enum Error {
+    Reqwest(reqwest::Error),
+    /// The response did not satisfy the success rule.
+    Unsuccessful(String),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Reqwest(e) => e.fmt(f),
+            Error::Unsuccessful(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::Reqwest(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
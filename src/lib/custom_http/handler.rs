@@ -0,0 +1,195 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use reqwest::{Method, StatusCode};
+
+use crate::http_client::sensitive_header_value;
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::template::Placeholders;
+use super::{Authentication, AuthenticationData, Error, RecordSpecification, SuccessRule};
+
+pub struct Handler {
+    http_client: reqwest::Client,
+}
+
+impl Handler {
+    async fn update_ip_address(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+    ) -> Result<(), crate::Error> {
+        let placeholders = Placeholders {
+            domain: domain.to_owned(),
+            host: name.to_owned(),
+            fqdn: match name {
+                "@" => domain.to_owned(),
+                _ => format!("{name}.{domain}"),
+            },
+            ip: ip.to_string(),
+            ttl: specification
+                .ttl
+                .map(|ttl| ttl.to_string())
+                .unwrap_or_default(),
+            type_: record_type.to_string(),
+        };
+
+        let method = match &specification.method {
+            Some(method) => method.0.clone(),
+            None => Method::GET,
+        };
+        let mut request = self
+            .http_client
+            .request(method, placeholders.render_url(&specification.url));
+        for (name, value) in &specification.headers {
+            request = request.header(name, placeholders.render(value));
+        }
+        if let Some(body) = &specification.body {
+            request = request.body(placeholders.render(body));
+        }
+        request = match &specification.authentication {
+            Some(Authentication::Basic { username, password }) => {
+                request.basic_auth(username, password.as_ref())
+            }
+            Some(Authentication::Bearer(token)) => request.bearer_auth(token),
+            Some(Authentication::Header { name, value }) => {
+                request.header(name, sensitive_header_value(value)?)
+            }
+            None => request,
+        };
+
+        let response = request.send().await.map_err(Error::from)?;
+        let status = response.status();
+        let text = response.text().await.map_err(Error::from)?;
+        if is_successful(specification.success.as_ref(), status, &text) {
+            Ok(())
+        } else {
+            Err(Error::Unsuccessful(format!("Request failed with Code {status:?}: {text}")).into())
+        }
+    }
+}
+
+fn is_successful(rule: Option<&SuccessRule>, status: StatusCode, text: &str) -> bool {
+    match rule {
+        None => status.is_success(),
+        Some(SuccessRule::StatusCodes(codes)) => codes.contains(&status.as_u16()),
+        Some(SuccessRule::BodyRegex(pattern)) => pattern.0.is_match(text),
+        Some(SuccessRule::JsonPointerEquals { pointer, value }) => {
+            serde_json::from_str::<serde_json::Value>(text)
+                .ok()
+                .and_then(|body| body.pointer(pointer).cloned())
+                .as_ref()
+                == Some(value)
+        }
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        _auth_data: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            http_client: client,
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(specification, domain, host, &IpAddr::V4(ip), RecordType::A)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            &specification.record_specification,
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn rule(rule: serde_json::Value) -> SuccessRule {
+        serde_json::from_value(rule).unwrap()
+    }
+
+    #[test]
+    fn requires_a_successful_status_by_default() {
+        assert!(is_successful(None, StatusCode::NO_CONTENT, ""));
+        assert!(!is_successful(None, StatusCode::FOUND, ""));
+    }
+
+    #[test]
+    fn checks_the_status_codes() {
+        let rule = rule(json!({"StatusCodes": [200, 409]}));
+        assert!(is_successful(Some(&rule), StatusCode::CONFLICT, ""));
+        assert!(!is_successful(Some(&rule), StatusCode::CREATED, ""));
+    }
+
+    #[test]
+    fn matches_the_body_regex_regardless_of_the_status() {
+        let rule = rule(json!({"BodyRegex": "^(good|nochg) "}));
+        assert!(is_successful(
+            Some(&rule),
+            StatusCode::OK,
+            "nochg 192.0.2.1"
+        ));
+        assert!(is_successful(
+            Some(&rule),
+            StatusCode::BAD_REQUEST,
+            "good 192.0.2.1"
+        ));
+        assert!(!is_successful(Some(&rule), StatusCode::OK, "badauth"));
+    }
+
+    #[test]
+    fn compares_the_value_at_the_json_pointer() {
+        let rule = rule(json!({"JsonPointerEquals": {"pointer": "/result/ok", "value": true}}));
+        let text = r#"{"result": {"ok": true}}"#;
+        assert!(is_successful(Some(&rule), StatusCode::OK, text));
+        assert!(!is_successful(
+            Some(&rule),
+            StatusCode::OK,
+            r#"{"result": {}}"#
+        ));
+        assert!(!is_successful(Some(&rule), StatusCode::OK, "not JSON"));
+    }
+
+    #[test]
+    fn rejects_invalid_specifications_when_reading_them() {
+        let specification = serde_json::from_value::<RecordSpecification>;
+        assert!(specification(json!({"url": "https://example.com", "method": "patch"})).is_ok());
+        assert!(specification(json!({"url": "https://example.com", "method": "GET IT"})).is_err());
+        assert!(specification(
+            json!({"url": "https://example.com", "success": {"BodyRegex": "(unclosed"}})
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,21 @@
+mod authentication_data;
+mod error;
+mod handler;
+mod record_specification;
+mod template;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::{
+    Authentication, HttpMethod, Pattern, RecordSpecification, SuccessRule,
+};
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("CustomHttp"));
+}
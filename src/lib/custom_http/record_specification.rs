@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use regex::Regex;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// Describes the request that updates a record. The URL, the header values and the body may
+/// contain the placeholders `{domain}`, `{host}`, `{fqdn}`, `{ip}`, `{ttl}` and `{type}`, whose
+/// values are percent-encoded in the URL.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {
+    /// HTTP method, `GET` if not set.
+    pub method: Option<HttpMethod>,
+    pub url: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<String>,
+    /// Value of the `{ttl}` placeholder, which is empty if not set.
+    pub ttl: Option<u32>,
+    /// Decides whether the update succeeded, a successful status code if not set.
+    pub success: Option<SuccessRule>,
+    pub authentication: Option<Authentication>,
+}
+
+/// Credentials sent along with the request, without any placeholders being replaced.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Authentication {
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+    Bearer(String),
+    Header {
+        name: String,
+        value: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum SuccessRule {
+    /// The response has one of the status codes.
+    StatusCodes(Vec<u16>),
+    /// The response body matches the regular expression.
+    BodyRegex(Pattern),
+    /// The value at the JSON pointer of the response body equals the given value.
+    JsonPointerEquals {
+        pointer: String,
+        value: serde_json::Value,
+    },
+}
+
+/// An HTTP method, which is checked when the specification is read.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "String", into = "String")]
+pub struct HttpMethod(pub Method);
+
+impl TryFrom<String> for HttpMethod {
+    type Error = String;
+
+    fn try_from(method: String) -> Result<HttpMethod, String> {
+        Method::from_bytes(method.to_uppercase().as_bytes())
+            .map(HttpMethod)
+            .map_err(|_| format!("Invalid HTTP method {method}"))
+    }
+}
+
+impl From<HttpMethod> for String {
+    fn from(method: HttpMethod) -> String {
+        method.0.to_string()
+    }
+}
+
+/// A regular expression, which is compiled once when the specification is read.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "String", into = "String")]
+pub struct Pattern(pub Regex);
+
+impl TryFrom<String> for Pattern {
+    type Error = regex::Error;
+
+    fn try_from(pattern: String) -> Result<Pattern, regex::Error> {
+        Regex::new(&pattern).map(Pattern)
+    }
+}
+
+impl From<Pattern> for String {
+    fn from(pattern: Pattern) -> String {
+        pattern.0.as_str().to_owned()
+    }
+}
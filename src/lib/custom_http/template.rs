@@ -0,0 +1,116 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Everything but the unreserved characters of RFC 3986, so a value cannot change the structure
+/// of the URL it is placed in.
+const URL_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Values substituted for the placeholders of a [`super::RecordSpecification`].
+pub struct Placeholders {
+    pub domain: String,
+    pub host: String,
+    pub fqdn: String,
+    pub ip: String,
+    pub ttl: String,
+    pub type_: String,
+}
+
+impl Placeholders {
+    /// Only the known placeholders are replaced, so literal braces, such as those of a JSON
+    /// body, are left as they are.
+    pub fn render(&self, template: &str) -> String {
+        self.render_with(template, str::to_owned)
+    }
+
+    /// Like [`Placeholders::render`], but with the values percent-encoded.
+    pub fn render_url(&self, template: &str) -> String {
+        self.render_with(template, |value| {
+            utf8_percent_encode(value, URL_COMPONENT).to_string()
+        })
+    }
+
+    /// Replaces the placeholders in a single pass, so values containing placeholders, e.g. a
+    /// host named `{ip}`, are not replaced in turn.
+    fn render_with(&self, template: &str, encode: impl Fn(&str) -> String) -> String {
+        let placeholders = [
+            ("{domain}", &self.domain),
+            ("{host}", &self.host),
+            ("{fqdn}", &self.fqdn),
+            ("{ip}", &self.ip),
+            ("{ttl}", &self.ttl),
+            ("{type}", &self.type_),
+        ];
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            rendered.push_str(&rest[..start]);
+            rest = &rest[start..];
+            match placeholders
+                .iter()
+                .find(|(placeholder, _)| rest.starts_with(placeholder))
+            {
+                Some((placeholder, value)) => {
+                    rendered.push_str(&encode(value));
+                    rest = &rest[placeholder.len()..];
+                }
+                None => {
+                    rendered.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(host: &str) -> Placeholders {
+        Placeholders {
+            domain: "example.com".to_owned(),
+            host: host.to_owned(),
+            fqdn: format!("{host}.example.com"),
+            ip: "2001:db8::1".to_owned(),
+            ttl: String::new(),
+            type_: "AAAA".to_owned(),
+        }
+    }
+
+    #[test]
+    fn replaces_every_placeholder() {
+        assert_eq!(
+            placeholders("www").render("{type} {fqdn} {host} {domain} {ip} {ttl}{ip}"),
+            "AAAA www.example.com www example.com 2001:db8::1 2001:db8::1"
+        );
+    }
+
+    #[test]
+    fn keeps_other_braces() {
+        assert_eq!(
+            placeholders("www").render(r#"{"content": "{ip}", "name": "{unknown}"}{"#),
+            r#"{"content": "2001:db8::1", "name": "{unknown}"}{"#
+        );
+    }
+
+    #[test]
+    fn does_not_replace_placeholders_in_values() {
+        assert_eq!(
+            placeholders("{ip}").render("{host} {fqdn}"),
+            "{ip} {ip}.example.com"
+        );
+    }
+
+    #[test]
+    fn encodes_the_values_in_urls() {
+        assert_eq!(
+            placeholders("a&b=c").render_url("https://example.com/update?host={fqdn}&ip={ip}"),
+            "https://example.com/update?host=a%26b%3Dc.example.com&ip=2001%3Adb8%3A%3A1"
+        );
+    }
+}
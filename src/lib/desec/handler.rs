@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use reqwest::{header, RequestBuilder, Response, StatusCode};
+use tokio::sync::Mutex;
+
+use crate::http_client::sensitive_header_value;
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::domain::Domain;
+use super::rrset::RRSet;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_URL: &str = "https://desec.io/api/v1";
+const MAX_RATE_LIMIT_RETRIES: usize = 3;
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    /// By domain, as every request counts against deSEC's rate limit. The lock is held while
+    /// fetching, so concurrent updates of a domain share one request.
+    minimum_ttls: Mutex<HashMap<String, u32>>,
+}
+
+impl Handler {
+    /// Sends the request built by `request`, waiting out `429 Too Many Requests` responses as
+    /// long as deSEC's `Retry-After` header asks for it.
+    async fn send(&self, request: impl Fn() -> RequestBuilder) -> Result<Response, Error> {
+        let mut failed_attempts = 0;
+        loop {
+            let response = request().send().await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            let retry_after = response
+                .headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(1);
+            if failed_attempts == MAX_RATE_LIMIT_RETRIES {
+                return Err(Error::RateLimited(retry_after));
+            }
+            failed_attempts += 1;
+            tokio::time::sleep(Duration::from_secs(retry_after)).await;
+        }
+    }
+
+    async fn minimum_ttl(&self, domain: &str) -> Result<u32, Error> {
+        let mut minimum_ttls = self.minimum_ttls.lock().await;
+        if let Some(minimum_ttl) = minimum_ttls.get(domain) {
+            return Ok(*minimum_ttl);
+        }
+        let response = self
+            .send(|| self.http_client.get(format!("{API_URL}/domains/{domain}/")))
+            .await?;
+        let text = check_status(response).await?;
+        let minimum_ttl = serde_json::from_str::<Domain>(&text)?.minimum_ttl;
+        minimum_ttls.insert(domain.to_owned(), minimum_ttl);
+        Ok(minimum_ttl)
+    }
+
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let minimum_ttl = self.minimum_ttl(domain).await?;
+        let ttl = ttl.map_or(minimum_ttl, |ttl| ttl.max(minimum_ttl));
+        let subname = match name {
+            "@" => "",
+            _ => name,
+        };
+        let rrsets = [RRSet {
+            subname,
+            type_: record_type,
+            ttl,
+            records: vec![ip.to_string()],
+        }];
+        let response = self
+            .send(|| {
+                self.http_client
+                    .patch(format!("{API_URL}/domains/{domain}/rrsets/"))
+                    .json(&rrsets)
+            })
+            .await?;
+        check_status(response).await?;
+        Ok(())
+    }
+}
+
+async fn check_status(response: Response) -> Result<String, Error> {
+    let status = response.status();
+    let text = response.text().await?;
+    if status.is_success() {
+        Ok(text)
+    } else {
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { token }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let mut headers = header::HeaderMap::new();
+        let auth_value = sensitive_header_value(&format!("Token {token}"))?;
+        headers.insert(header::AUTHORIZATION, auth_value);
+
+        let client = http_client_factory
+            .builder()?
+            .default_headers(headers)
+            .build()?;
+
+        Ok(Handler {
+            http_client: client,
+            minimum_ttls: Mutex::new(HashMap::new()),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
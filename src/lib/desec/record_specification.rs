@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+/// `ttl` is raised to the domain's `minimum_ttl` if it is lower; `None` uses the minimum.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {
+    pub ttl: Option<u32>,
+}
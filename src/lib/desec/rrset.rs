@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use crate::RecordType;
+
+#[derive(Serialize, Debug)]
+pub struct RRSet<'a> {
+    pub subname: &'a str,
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub ttl: u32,
+    pub records: Vec<String>,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::RecordType;
+
+#[derive(Serialize, Debug)]
+pub struct DomainRecordRequest<'a> {
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub name: &'a str,
+    pub data: String,
+    pub ttl: u32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DomainRecord {
+    pub id: u64,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DomainRecordsPage {
+    pub domain_records: Vec<DomainRecord>,
+    #[serde(default)]
+    pub links: Links,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct Links {
+    #[serde(default)]
+    pub pages: Pages,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct Pages {
+    pub next: Option<url::Url>,
+}
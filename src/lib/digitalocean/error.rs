@@ -0,0 +1,49 @@
+pub enum Error {
+    Reqwest(reqwest::Error),
+    SerdeJson(serde_json::Error),
+    Request(String),
+    Url(url::ParseError),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Reqwest(e) => e.fmt(f),
+            Error::SerdeJson(e) => e.fmt(f),
+            Error::Request(e) => e.fmt(f),
+            Error::Url(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::Reqwest(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::SerdeJson(err)
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(err: url::ParseError) -> Error {
+        Error::Url(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
@@ -6,8 +6,10 @@ pub enum Error {
     ReqwestError(reqwest::Error),
     ResolverError(String),
     AuthenticationError(String),
-    GoDaddyError(crate::godaddy::Error),
-    YDns(crate::ydns::Error),
+    ConfigurationError(String),
+    /// An error of a provider, e.g. a `godaddy::Error`. Providers outside of this crate can return
+    /// their own error types.
+    Provider(Box<dyn std::error::Error + Send + Sync>),
 }
 
 impl From<preferences::PreferencesError> for Error {
@@ -27,15 +29,3 @@ impl From<(Error, usize)> for Error {
         error.0
     }
 }
-
-impl From<crate::godaddy::Error> for Error {
-    fn from(error: crate::godaddy::Error) -> Self {
-        Error::GoDaddyError(error)
-    }
-}
-
-impl From<crate::ydns::Error> for Error {
-    fn from(error: crate::ydns::Error) -> Self {
-        Error::YDns(error)
-    }
-}
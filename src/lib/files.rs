@@ -0,0 +1,87 @@
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tokio::io::AsyncWriteExt;
+
+/// Distinguishes the temporary files of concurrent writes within this process.
+static TEMPORARY_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// Replaces the file at `path` with `contents` by writing them to a temporary file in the same
+/// directory and renaming it, so readers like DNS servers never see a partially written file.
+/// The permissions of an existing file are kept.
+pub async fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let file_name = path.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is not a file", path.display()),
+        )
+    })?;
+    let temporary_path = path.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = write_and_rename(path, &temporary_path, contents).await;
+    if result.is_err() {
+        let _ = tokio::fs::remove_file(&temporary_path).await;
+    }
+    result
+}
+
+async fn write_and_rename(path: &Path, temporary_path: &Path, contents: &str) -> io::Result<()> {
+    let metadata = match tokio::fs::metadata(path).await {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        metadata => Some(metadata?),
+    };
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(temporary_path)
+        .await?;
+    file.write_all(contents.as_bytes()).await?;
+    file.sync_all().await?;
+    drop(file);
+
+    if let Some(metadata) = metadata {
+        tokio::fs::set_permissions(temporary_path, metadata.permissions()).await?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            // Only succeeds for privileged users or if the owner is us anyway, otherwise the file
+            // is owned by us from now on.
+            let _ = std::os::unix::fs::chown(
+                temporary_path,
+                Some(metadata.uid()),
+                Some(metadata.gid()),
+            );
+        }
+    }
+    tokio::fs::rename(temporary_path, path).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn replaces_the_file_and_keeps_its_permissions() {
+        let directory = std::env::temp_dir().join(format!("dyndns-files-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("hosts");
+        std::fs::write(&path, "old").unwrap();
+        let mut permissions = std::fs::metadata(&path).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&path, permissions.clone()).unwrap();
+
+        write_atomically(&path, "new").await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions(), permissions);
+        assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 1);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,57 @@
+use std::net::IpAddr;
+
+use sha1::{Digest, Sha1};
+
+use super::{AccountCredentials, Error};
+
+const API_URL: &str = "https://freedns.afraid.org/api/";
+
+/// An entry of the account's list of dynamic DNS records.
+#[derive(Debug)]
+pub struct DynamicRecord {
+    pub host: String,
+    pub address: Option<IpAddr>,
+    pub update_url: url::Url,
+}
+
+/// Lists the account's dynamic DNS records, which come as `host|address|update URL` lines.
+pub async fn dynamic_records(
+    http_client: &reqwest::Client,
+    credentials: &AccountCredentials,
+) -> Result<Vec<DynamicRecord>, Error> {
+    let sha = format!(
+        "{:x}",
+        Sha1::digest(format!(
+            "{}|{}",
+            credentials.username.to_lowercase(),
+            credentials.password
+        ))
+    );
+    let text = http_client
+        .get(API_URL)
+        .query(&[("action", "getdyndns"), ("v", "2"), ("sha", &sha)])
+        .send()
+        .await?
+        .text()
+        .await?;
+    if text.trim_start().starts_with("ERROR") {
+        return Err(Error::Account(text.trim().to_owned()));
+    }
+
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = line.split('|');
+            let (Some(host), Some(address), Some(update_url)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(Error::Account(format!("Unexpected record line: {line}")));
+            };
+            Ok(DynamicRecord {
+                host: host.to_owned(),
+                address: address.parse().ok(),
+                update_url: url::Url::parse(update_url.trim())?,
+            })
+        })
+        .collect()
+}
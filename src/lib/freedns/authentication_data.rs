@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// Only needed by records using [`super::RecordSpecification::Account`].
+    pub account: Option<AccountCredentials>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AccountCredentials {
+    pub username: String,
+    pub password: String,
+}
@@ -0,0 +1,46 @@
+pub enum Error {
+    Reqwest(reqwest::Error),
+    /// The update URL answered with something else than an update or unchanged notice.
+    Update(String),
+    Account(String),
+    RecordNotFound(String),
+    Url(url::ParseError),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Reqwest(e) => e.fmt(f),
+            Error::Update(e) => e.fmt(f),
+            Error::Account(e) => e.fmt(f),
+            Error::RecordNotFound(e) => e.fmt(f),
+            Error::Url(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::Reqwest(err)
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(err: url::ParseError) -> Error {
+        Error::Url(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
@@ -0,0 +1,124 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, UpdateHandler};
+
+use super::account::dynamic_records;
+use super::{AccountCredentials, AuthenticationData, Error, RecordSpecification, UpdateOutcome};
+
+const SYNC_URL_V4: &str = "https://sync.afraid.org/u";
+const SYNC_URL_V6: &str = "https://v6.sync.afraid.org/u";
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    account: Option<AccountCredentials>,
+}
+
+impl Handler {
+    async fn call_update_url(
+        &self,
+        mut update_url: url::Url,
+        ip: &IpAddr,
+    ) -> Result<UpdateOutcome, Error> {
+        update_url
+            .query_pairs_mut()
+            .append_pair("address", &ip.to_string());
+        let text = self
+            .http_client
+            .get(update_url)
+            .send()
+            .await?
+            .text()
+            .await?;
+        UpdateOutcome::parse(&text)
+    }
+
+    async fn account_update_url(&self, fqdn: &str, ip: &IpAddr) -> Result<url::Url, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or_else(|| Error::Account("No account credentials provided.".to_owned()))?;
+        let records = dynamic_records(&self.http_client, account).await?;
+        // A host with both an A and an AAAA record is listed twice, told apart by its address.
+        records
+            .into_iter()
+            .filter(|record| record.host.eq_ignore_ascii_case(fqdn))
+            .find(|record| {
+                record
+                    .address
+                    .map_or(true, |address| address.is_ipv4() == ip.is_ipv4())
+            })
+            .map(|record| record.update_url)
+            .ok_or_else(|| Error::RecordNotFound(format!("{fqdn} is not a dynamic record")))
+    }
+
+    /// Like the [`UpdateHandler`] methods, but tells whether FreeDNS changed the address.
+    pub async fn update_ip_address(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+    ) -> Result<UpdateOutcome, Error> {
+        let update_url = match specification {
+            RecordSpecification::UpdateToken(token) => {
+                let sync_url = match ip {
+                    IpAddr::V4(_) => SYNC_URL_V4,
+                    IpAddr::V6(_) => SYNC_URL_V6,
+                };
+                url::Url::parse(&format!("{sync_url}/{token}/"))?
+            }
+            RecordSpecification::Account => {
+                let fqdn = match name {
+                    "@" => domain.to_owned(),
+                    _ => format!("{name}.{domain}"),
+                };
+                self.account_update_url(&fqdn, ip).await?
+            }
+        };
+        self.call_update_url(update_url, ip).await
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { account }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            http_client: client,
+            account: account.clone(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(specification, domain, host, &IpAddr::V4(ip))
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            &specification.record_specification,
+            domain,
+            host,
+            &IpAddr::V6(ip),
+        )
+        .await?;
+        Ok(())
+    }
+}
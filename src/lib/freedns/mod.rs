@@ -0,0 +1,21 @@
+mod account;
+mod authentication_data;
+mod error;
+mod handler;
+mod record_specification;
+mod update_outcome;
+
+pub use authentication_data::{AccountCredentials, AuthenticationData};
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::RecordSpecification;
+pub use update_outcome::UpdateOutcome;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("FreeDns"));
+}
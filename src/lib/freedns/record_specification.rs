@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum RecordSpecification {
+    /// Token of the record's randomized v2 update URL, the part after `/u/`.
+    UpdateToken(String),
+    /// Looks the record's update URL up in the account's list of dynamic DNS records.
+    Account,
+}
@@ -0,0 +1,59 @@
+use super::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateOutcome {
+    Updated,
+    Unchanged,
+}
+
+impl UpdateOutcome {
+    /// Interprets the plain text answer of the update URLs, which also report an unchanged
+    /// address as an `ERROR:` line.
+    pub fn parse(response: &str) -> Result<UpdateOutcome, Error> {
+        let response = response.trim();
+        if response.starts_with("Updated") {
+            Ok(UpdateOutcome::Updated)
+        } else if response.contains("has not changed") || response.starts_with("No IP change") {
+            Ok(UpdateOutcome::Unchanged)
+        } else {
+            Err(Error::Update(response.to_owned()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_updates() {
+        let response = "Updated 1 host(s) [www.mooo.com] to 192.0.2.1 in 0.263 seconds\n";
+        assert_eq!(
+            UpdateOutcome::parse(response).unwrap(),
+            UpdateOutcome::Updated
+        );
+    }
+
+    #[test]
+    fn recognizes_unchanged_addresses() {
+        for response in [
+            "ERROR: Address 192.0.2.1 has not changed.",
+            "No IP change detected for www.mooo.com with IP 192.0.2.1, skipping update",
+        ] {
+            assert_eq!(
+                UpdateOutcome::parse(response).unwrap(),
+                UpdateOutcome::Unchanged
+            );
+        }
+    }
+
+    #[test]
+    fn reports_errors() {
+        let response = "ERROR: Unable to locate this record (changed password recently?)";
+        assert!(matches!(
+            UpdateOutcome::parse(response),
+            Err(Error::Update(message)) if message == response
+        ));
+        assert!(UpdateOutcome::parse("").is_err());
+    }
+}
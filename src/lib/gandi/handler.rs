@@ -0,0 +1,104 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use reqwest::header;
+
+use crate::http_client::sensitive_header_value;
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::rrset::RRSet;
+use super::{AuthenticationData, Error, SpecificationV4, SpecificationV6};
+
+const API_URL: &str = "https://api.gandi.net/v5/livedns";
+
+pub struct Handler {
+    http_client: reqwest::Client,
+}
+
+impl Handler {
+    async fn update_rrset(
+        &self,
+        domain: &str,
+        name: &str,
+        record_type: RecordType,
+        rrset: &RRSet,
+    ) -> Result<(), Error> {
+        let response = self
+            .http_client
+            .put(format!(
+                "{API_URL}/domains/{domain}/records/{name}/{record_type}"
+            ))
+            .json(rrset)
+            .send()
+            .await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let text = response.text().await?;
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
+
+impl UpdateHandler<AuthenticationData, SpecificationV4, SpecificationV6> for Handler {
+    fn new(
+        AuthenticationData {
+            personal_access_token,
+        }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let mut headers = header::HeaderMap::new();
+        let auth_value = sensitive_header_value(&format!("Bearer {personal_access_token}"))?;
+        headers.insert(header::AUTHORIZATION, auth_value);
+
+        let client = http_client_factory
+            .builder()?
+            .default_headers(headers)
+            .build()?;
+
+        Ok(Handler {
+            http_client: client,
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &SpecificationV4,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        let rrset = RRSet {
+            rrset_values: std::iter::once(ip)
+                .chain(specification.additional_values.iter().copied())
+                .map(|ip| ip.to_string())
+                .collect(),
+            rrset_ttl: specification.ttl,
+        };
+        self.update_rrset(domain, host, RecordType::A, &rrset)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<SpecificationV6>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        let specification = &specification.record_specification;
+        let rrset = RRSet {
+            rrset_values: std::iter::once(ip)
+                .chain(specification.additional_values.iter().copied())
+                .map(|ip| ip.to_string())
+                .collect(),
+            rrset_ttl: specification.ttl,
+        };
+        self.update_rrset(domain, host, RecordType::AAAA, &rrset)
+            .await?;
+        Ok(())
+    }
+}
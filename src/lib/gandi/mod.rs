@@ -0,0 +1,19 @@
+mod authentication_data;
+mod error;
+mod handler;
+mod record_specification;
+mod rrset;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::{SpecificationV4, SpecificationV6};
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        SpecificationV4,
+        SpecificationV6,
+    >("Gandi"));
+}
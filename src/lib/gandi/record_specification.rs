@@ -0,0 +1,18 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use serde::{Deserialize, Serialize};
+
+/// `additional_values` are published next to the dynamic address in the same RRset.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SpecificationV4 {
+    pub ttl: Option<u32>,
+    #[serde(default)]
+    pub additional_values: Vec<Ipv4Addr>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SpecificationV6 {
+    pub ttl: Option<u32>,
+    #[serde(default)]
+    pub additional_values: Vec<Ipv6Addr>,
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+#[derive(Serialize, Debug)]
+pub struct RRSet {
+    pub rrset_values: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrset_ttl: Option<u32>,
+}
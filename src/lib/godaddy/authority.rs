@@ -1,7 +1,7 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
-use std::panic::panic_any;
 
-use crate::RecordType;
+use crate::http_client::sensitive_header_value;
+use crate::{HttpClientFactory, RecordType};
 
 use super::dns_data::DnsData;
 use super::dns_record::DnsRecord;
@@ -13,25 +13,26 @@ pub struct Authority {
 }
 
 impl Authority {
-    pub fn new<'a>(api_key: &'a str, api_secret: &'a str, api_url: url::Url) -> Authority {
+    pub fn new<'a>(
+        api_key: &'a str,
+        api_secret: &'a str,
+        api_url: url::Url,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Authority, crate::Error> {
         let mut headers = reqwest::header::HeaderMap::new();
-        let auth_value = match reqwest::header::HeaderValue::from_str(
-            &("sso-key ".to_owned() + api_key + ":" + api_secret),
-        ) {
-            Err(e) => panic_any(e),
-            Ok(h) => h,
-        };
+        let auth_value =
+            sensitive_header_value(&("sso-key ".to_owned() + api_key + ":" + api_secret))?;
         headers.insert(reqwest::header::AUTHORIZATION, auth_value);
 
-        let client = match reqwest::Client::builder().default_headers(headers).build() {
-            Err(e) => panic_any(e),
-            Ok(c) => c,
-        };
+        let client = http_client_factory
+            .builder()?
+            .default_headers(headers)
+            .build()?;
 
-        Authority {
+        Ok(Authority {
             api_url,
             http_client: client,
-        }
+        })
     }
 
     pub async fn update_ipv4_address(
@@ -74,7 +75,7 @@ impl Authority {
                     /records/"
                         + &record_type.to_string()
                         + "\
-                    /" + &name
+                    /" + name
                         + ""),
                 )
                 .json(&[DnsRecord::new(
@@ -98,7 +99,7 @@ impl Authority {
         } else {
             unreachable!()
         };
-        return Ok(ipv4);
+        Ok(ipv4)
     }
 
     pub async fn get_ipv6_address(&self, domain: &str, name: &str) -> Result<Ipv6Addr, Error> {
@@ -108,7 +109,7 @@ impl Authority {
         } else {
             unreachable!()
         };
-        return Ok(ipv6);
+        Ok(ipv6)
     }
 
     async fn get_address(
@@ -127,7 +128,7 @@ impl Authority {
                     /records/"
                         + &record_type.to_string()
                         + "\
-                    /" + &name
+                    /" + name
                         + ""),
                 )
                 .send()
@@ -1,6 +1,6 @@
 use std::net::{Ipv4Addr, Ipv6Addr};
 
-use crate::{Error, RecordSpecificationV6Adapter, UpdateHandler};
+use crate::{Error, HttpClientFactory, RecordSpecificationV6Adapter, UpdateHandler};
 
 use super::record_specification::{SpecificationV4, SpecificationV6};
 use super::{AuthenticationData, Authority};
@@ -10,15 +10,19 @@ pub struct Handler {
 }
 
 impl UpdateHandler<AuthenticationData, SpecificationV4, SpecificationV6> for Handler {
-    fn new(auth_data: &AuthenticationData) -> Handler {
+    fn new(
+        auth_data: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, Error> {
         let handler = Handler {
             authority: Authority::new(
                 auth_data.api_key.as_str(),
                 auth_data.api_secret.as_str(),
                 auth_data.api_url.clone().into(),
-            ),
+                http_client_factory,
+            )?,
         };
-        handler
+        Ok(handler)
     }
 
     async fn update_ipv6_record(
@@ -13,3 +13,12 @@ pub use error::Error;
 pub use handler::Handler;
 pub use predefined_url::PredefinedUrl;
 pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("GoDaddy"));
+}
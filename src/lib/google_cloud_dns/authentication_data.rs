@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// JSON key file of the service account, as downloaded from the Cloud Console.
+    pub service_account_key_file: PathBuf,
+    /// Defaults to the `project_id` of the key.
+    pub project: Option<String>,
+    /// Overrides the `token_uri` of the key.
+    pub token_url: Option<url::Url>,
+    /// Defaults to `https://dns.googleapis.com/dns/v1`.
+    pub api_url: Option<url::Url>,
+}
@@ -0,0 +1,59 @@
+pub enum Error {
+    Reqwest(reqwest::Error),
+    SerdeJson(serde_json::Error),
+    Io(std::io::Error),
+    Jwt(jsonwebtoken::errors::Error),
+    Request(String),
+    ZoneNotFound(String),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Reqwest(e) => e.fmt(f),
+            Error::SerdeJson(e) => e.fmt(f),
+            Error::Io(e) => e.fmt(f),
+            Error::Jwt(e) => e.fmt(f),
+            Error::Request(e) => e.fmt(f),
+            Error::ZoneNotFound(domain) => write!(f, "No managed zone found for {domain}"),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::Reqwest(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::SerdeJson(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for Error {
+    fn from(err: jsonwebtoken::errors::Error) -> Error {
+        Error::Jwt(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
@@ -0,0 +1,294 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use reqwest::RequestBuilder;
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::managed_zone::ManagedZonesList;
+use super::resource_record_set::{Change, ResourceRecordSet, ResourceRecordSetsList};
+use super::token_source::TokenSource;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_URL: &str = "https://dns.googleapis.com/dns/v1";
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    token_source: TokenSource,
+    project: Option<String>,
+    api_url: String,
+}
+
+impl Handler {
+    fn project(&self) -> Result<&str, Error> {
+        match &self.project {
+            Some(project) => Ok(project),
+            None => Ok(&self.token_source.key()?.project_id),
+        }
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<String, Error> {
+        let access_token = self.token_source.access_token().await?;
+        let response = request.bearer_auth(access_token).send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if status.is_success() {
+            Ok(text)
+        } else {
+            Err(Error::Request(format!(
+                "Request failed with Code {status:?}: {text}"
+            )))
+        }
+    }
+
+    async fn managed_zone(&self, domain: &str) -> Result<String, Error> {
+        let text = self
+            .send(
+                self.http_client
+                    .get(format!(
+                        "{}/projects/{}/managedZones",
+                        self.api_url,
+                        self.project()?
+                    ))
+                    .query(&[("dnsName", format!("{domain}."))]),
+            )
+            .await?;
+        let zones: ManagedZonesList = serde_json::from_str(&text)?;
+        zones
+            .managed_zones
+            .into_iter()
+            .next()
+            .map(|zone| zone.name)
+            .ok_or_else(|| Error::ZoneNotFound(domain.to_owned()))
+    }
+
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: u32,
+    ) -> Result<(), Error> {
+        let zone_url = format!(
+            "{}/projects/{}/managedZones/{}",
+            self.api_url,
+            self.project()?,
+            self.managed_zone(domain).await?
+        );
+        let fqdn = match name {
+            "@" => format!("{domain}."),
+            _ => format!("{name}.{domain}."),
+        };
+
+        let text = self
+            .send(
+                self.http_client
+                    .get(format!("{zone_url}/rrsets"))
+                    .query(&[("name", fqdn.as_str()), ("type", &record_type.to_string())]),
+            )
+            .await?;
+        let existing: ResourceRecordSetsList = serde_json::from_str(&text)?;
+        let addition = ResourceRecordSet {
+            name: fqdn,
+            type_: record_type,
+            ttl,
+            rrdatas: vec![ip.to_string()],
+        };
+        if existing.rrsets.contains(&addition) {
+            return Ok(());
+        }
+
+        // Cloud DNS only accepts deletions that exactly match the current record set.
+        let change = Change {
+            additions: vec![addition],
+            deletions: existing.rrsets,
+        };
+        self.send(
+            self.http_client
+                .post(format!("{zone_url}/changes"))
+                .json(&change),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        auth_data: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            token_source: TokenSource::new(
+                client.clone(),
+                auth_data.service_account_key_file.clone(),
+                auth_data.token_url.clone(),
+            ),
+            http_client: client,
+            project: auth_data.project.clone(),
+            api_url: auth_data
+                .api_url
+                .as_ref()
+                .map_or(API_URL, url::Url::as_str)
+                .trim_end_matches('/')
+                .to_owned(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_json, body_string_contains, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    /// Only used to sign the JWTs sent to the mocked token endpoint.
+    const PRIVATE_KEY: &str = include_str!("test_key.pem");
+
+    async fn handler(server: &MockServer, name: &str) -> Handler {
+        let key_file = std::env::temp_dir().join(format!(
+            "dyndns-google-cloud-dns-{name}-{}.json",
+            std::process::id()
+        ));
+        let key = json!({
+            "project_id": "project",
+            "client_email": "dyndns@project.iam.gserviceaccount.com",
+            "private_key": PRIVATE_KEY,
+            "token_uri": format!("{}/token", server.uri()),
+        });
+        std::fs::write(&key_file, key.to_string()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .and(body_string_contains("grant_type=urn"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"access_token": "token", "expires_in": 3600})),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/projects/project/managedZones"))
+            .and(query_param("dnsName", "example.com."))
+            .and(header("Authorization", "Bearer token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"managedZones": [{"name": "zone"}]})),
+            )
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/projects/project/managedZones/zone/rrsets"))
+            .and(query_param("name", "www.example.com."))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"rrsets": [{
+                "name": "www.example.com.", "type": "A", "ttl": 300, "rrdatas": ["192.0.2.1"]
+            }]})))
+            .mount(server)
+            .await;
+
+        Handler::new(
+            &AuthenticationData {
+                service_account_key_file: key_file,
+                project: None,
+                token_url: None,
+                api_url: Some(url::Url::parse(&server.uri()).unwrap()),
+            },
+            &HttpClientFactory::default(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn replaces_the_record_set() {
+        let server = MockServer::start().await;
+        let handler = handler(&server, "update").await;
+        Mock::given(method("POST"))
+            .and(path("/projects/project/managedZones/zone/changes"))
+            .and(body_json(json!({
+                "additions": [{
+                    "name": "www.example.com.", "type": "A", "ttl": 300, "rrdatas": ["198.51.100.1"]
+                }],
+                "deletions": [{
+                    "name": "www.example.com.", "type": "A", "ttl": 300, "rrdatas": ["192.0.2.1"]
+                }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1));
+        handler
+            .update_ip_address("example.com", "www", &ip, RecordType::A, 300)
+            .await
+            .unwrap();
+        // Up to date, so no change is sent, and the token is reused.
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        handler
+            .update_ip_address("example.com", "www", &ip, RecordType::A, 300)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn reports_rejected_changes() {
+        let server = MockServer::start().await;
+        let handler = handler(&server, "rejected").await;
+        Mock::given(method("POST"))
+            .and(path("/projects/project/managedZones/zone/changes"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("Forbidden"))
+            .mount(&server)
+            .await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1));
+        let result = handler
+            .update_ip_address("example.com", "www", &ip, RecordType::A, 300)
+            .await;
+        assert!(
+            matches!(&result, Err(Error::Request(message)) if message.contains("403")),
+            "{result:?}"
+        );
+    }
+}
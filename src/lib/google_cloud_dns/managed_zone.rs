@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct ManagedZone {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedZonesList {
+    #[serde(default)]
+    pub managed_zones: Vec<ManagedZone>,
+}
@@ -0,0 +1,23 @@
+mod authentication_data;
+mod error;
+mod handler;
+mod managed_zone;
+mod record_specification;
+mod resource_record_set;
+mod service_account_key;
+mod token_source;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::RecordSpecification;
+pub use service_account_key::ServiceAccountKey;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("GoogleCloudDns"));
+}
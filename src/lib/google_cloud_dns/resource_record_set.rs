@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::RecordType;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ResourceRecordSet {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub ttl: u32,
+    pub rrdatas: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ResourceRecordSetsList {
+    #[serde(default)]
+    pub rrsets: Vec<ResourceRecordSet>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Change {
+    pub additions: Vec<ResourceRecordSet>,
+    pub deletions: Vec<ResourceRecordSet>,
+}
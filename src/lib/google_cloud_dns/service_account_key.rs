@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// The fields of a service account's JSON key that are needed to mint access tokens.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ServiceAccountKey {
+    pub project_id: String,
+    pub client_email: String,
+    pub private_key: String,
+    pub token_uri: url::Url,
+}
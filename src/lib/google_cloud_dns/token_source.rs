@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::Serialize;
+
+use crate::token_cache::{TokenCache, TokenResponse};
+
+use super::{Error, ServiceAccountKey};
+
+const SCOPE: &str = "https://www.googleapis.com/auth/ndev.clouddns.readwrite";
+const GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+const TOKEN_LIFETIME: Duration = Duration::from_secs(3600);
+
+#[derive(Serialize, Debug)]
+struct Claims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+/// Mints OAuth2 access tokens from a service account key by exchanging a self-signed RS256 JWT.
+pub struct TokenSource {
+    http_client: reqwest::Client,
+    key_file: PathBuf,
+    token_url: Option<url::Url>,
+    key: OnceLock<ServiceAccountKey>,
+    token_cache: TokenCache,
+}
+
+impl TokenSource {
+    pub fn new(
+        http_client: reqwest::Client,
+        key_file: PathBuf,
+        token_url: Option<url::Url>,
+    ) -> TokenSource {
+        TokenSource {
+            http_client,
+            key_file,
+            token_url,
+            key: OnceLock::new(),
+            token_cache: TokenCache::default(),
+        }
+    }
+
+    pub fn key(&self) -> Result<&ServiceAccountKey, Error> {
+        if let Some(key) = self.key.get() {
+            return Ok(key);
+        }
+        let key = serde_json::from_slice(&std::fs::read(&self.key_file)?)?;
+        Ok(self.key.get_or_init(|| key))
+    }
+
+    pub async fn access_token(&self) -> Result<String, Error> {
+        self.token_cache
+            .get_or_request(self.request_access_token())
+            .await
+    }
+
+    async fn request_access_token(&self) -> Result<TokenResponse, Error> {
+        let key = self.key()?;
+        let token_url = self.token_url.as_ref().unwrap_or(&key.token_uri);
+        let iat = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        let claims = Claims {
+            iss: &key.client_email,
+            scope: SCOPE,
+            aud: token_url.as_str(),
+            iat,
+            exp: iat + TOKEN_LIFETIME.as_secs(),
+        };
+        let assertion = jsonwebtoken::encode(
+            &Header::new(Algorithm::RS256),
+            &claims,
+            &EncodingKey::from_rsa_pem(key.private_key.as_bytes())?,
+        )?;
+
+        let response = self
+            .http_client
+            .post(token_url.clone())
+            .form(&[("grant_type", GRANT_TYPE), ("assertion", &assertion)])
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(Error::Request(format!(
+                "Token request failed with Code {status:?}: {text}"
+            )));
+        }
+        Ok(serde_json::from_str(&text)?)
+    }
+}
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// The `/etc/hosts`-style file to maintain, e.g. an `addn-hosts` file of dnsmasq.
+    pub path: PathBuf,
+    /// Name of the block the entries are kept in, `dyndns-rs` if not set.
+    pub marker: Option<String>,
+}
@@ -0,0 +1,69 @@
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::PathBuf;
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, UpdateHandler};
+
+use super::hosts::HostsFile;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const DEFAULT_MARKER: &str = "dyndns-rs";
+
+pub struct Handler {
+    path: PathBuf,
+    marker: String,
+}
+
+impl Handler {
+    async fn update_ip_address(&self, domain: &str, name: &str, ip: IpAddr) -> Result<(), Error> {
+        let hostname = match name {
+            "@" => domain.to_owned(),
+            _ => format!("{name}.{domain}"),
+        };
+        // A missing file is created with nothing but our block.
+        let content = match tokio::fs::read_to_string(&self.path).await {
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            content => content?,
+        };
+        let mut hosts_file = HostsFile::parse(&content, &self.marker)?;
+        if hosts_file.set(&hostname, ip) {
+            crate::files::write_atomically(&self.path, &hosts_file.render(&self.marker)).await?;
+        }
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { path, marker }: &AuthenticationData,
+        _http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        Ok(Handler {
+            path: path.clone(),
+            marker: marker.as_deref().unwrap_or(DEFAULT_MARKER).to_owned(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        _specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(domain, host, IpAddr::V4(ip)).await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(domain, host, IpAddr::V6(ip)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use std::net::IpAddr;
+
+use super::Error;
+
+/// A hosts file whose entries between `# BEGIN <marker>` and `# END <marker>` are managed by
+/// us, while everything outside of the block is kept untouched.
+pub struct HostsFile {
+    before: Vec<String>,
+    block: Vec<String>,
+    after: Vec<String>,
+}
+
+impl HostsFile {
+    pub fn parse(content: &str, marker: &str) -> Result<HostsFile, Error> {
+        let begin = format!("# BEGIN {marker}");
+        let end = format!("# END {marker}");
+        let lines: Vec<String> = content.lines().map(str::to_owned).collect();
+        let positions = |marker: &str| -> Vec<usize> {
+            (0..lines.len())
+                .filter(|&index| lines[index].trim() == marker)
+                .collect()
+        };
+
+        match (positions(&begin).as_slice(), positions(&end).as_slice()) {
+            ([], []) => Ok(HostsFile {
+                before: lines,
+                block: Vec::new(),
+                after: Vec::new(),
+            }),
+            (&[begin_index], &[end_index]) if begin_index < end_index => Ok(HostsFile {
+                before: lines[..begin_index].to_vec(),
+                block: lines[begin_index + 1..end_index].to_vec(),
+                after: lines[end_index + 1..].to_vec(),
+            }),
+            _ => Err(Error::MalformedBlock(format!(
+                "Expected exactly one \"{begin}\" followed by \"{end}\""
+            ))),
+        }
+    }
+
+    /// Points `hostname` to `ip`, replacing any address of the same family it had in the
+    /// block. Returns whether the block changed.
+    pub fn set(&mut self, hostname: &str, ip: IpAddr) -> bool {
+        let mut up_to_date = false;
+        let mut changed = false;
+        let mut block = Vec::with_capacity(self.block.len() + 1);
+
+        for line in self.block.drain(..) {
+            let mut fields = line.split_whitespace();
+            let entry_ip = fields.next().and_then(|field| field.parse::<IpAddr>().ok());
+            let Some(entry_ip) = entry_ip.filter(|entry_ip| entry_ip.is_ipv4() == ip.is_ipv4())
+            else {
+                block.push(line);
+                continue;
+            };
+            let names: Vec<&str> = fields.collect();
+            if !names.iter().any(|name| name.eq_ignore_ascii_case(hostname)) {
+                block.push(line);
+                continue;
+            }
+            if entry_ip == ip && !up_to_date {
+                up_to_date = true;
+                block.push(line);
+                continue;
+            }
+
+            changed = true;
+            let remaining: Vec<&str> = names
+                .into_iter()
+                .filter(|name| !name.eq_ignore_ascii_case(hostname))
+                .collect();
+            if !remaining.is_empty() {
+                block.push(format!("{entry_ip} {}", remaining.join(" ")));
+            }
+        }
+
+        if !up_to_date {
+            changed = true;
+            block.push(format!("{ip} {hostname}"));
+        }
+        self.block = block;
+        changed
+    }
+
+    pub fn render(&self, marker: &str) -> String {
+        let mut lines: Vec<String> = self.before.clone();
+        lines.push(format!("# BEGIN {marker}"));
+        lines.extend(self.block.iter().cloned());
+        lines.push(format!("# END {marker}"));
+        lines.extend(self.after.iter().cloned());
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(content: &str, hostname: &str, ip: &str) -> (bool, String) {
+        let mut hosts_file = HostsFile::parse(content, "dyndns-rs").unwrap();
+        let changed = hosts_file.set(hostname, ip.parse().unwrap());
+        (changed, hosts_file.render("dyndns-rs"))
+    }
+
+    #[test]
+    fn appends_the_block_to_a_file_without_one() {
+        assert_eq!(
+            set("127.0.0.1 localhost", "www.example.com", "192.0.2.1"),
+            (
+                true,
+                "127.0.0.1 localhost\n# BEGIN dyndns-rs\n192.0.2.1 www.example.com\n# END dyndns-rs\n"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn replaces_the_address_of_the_same_family_only() {
+        let content = "# BEGIN dyndns-rs\n192.0.2.1 www.example.com\n2001:db8::1 www.example.com\n# END dyndns-rs\n::1 localhost";
+        assert_eq!(
+            set(content, "WWW.example.com", "198.51.100.1"),
+            (
+                true,
+                "# BEGIN dyndns-rs\n2001:db8::1 www.example.com\n198.51.100.1 WWW.example.com\n# END dyndns-rs\n::1 localhost\n"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_the_other_names_of_an_entry() {
+        let content = "# BEGIN dyndns-rs\n192.0.2.1 www.example.com example.com\n# END dyndns-rs";
+        assert_eq!(
+            set(content, "www.example.com", "198.51.100.1"),
+            (
+                true,
+                "# BEGIN dyndns-rs\n192.0.2.1 example.com\n198.51.100.1 www.example.com\n# END dyndns-rs\n"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn leaves_an_up_to_date_entry_unchanged() {
+        let content = "# BEGIN dyndns-rs\n192.0.2.1 www.example.com\n# END dyndns-rs\n";
+        assert_eq!(
+            set(content, "www.example.com", "192.0.2.1"),
+            (false, content.to_owned())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_blocks() {
+        for content in [
+            "# BEGIN dyndns-rs",
+            "# END dyndns-rs",
+            "# END dyndns-rs\n# BEGIN dyndns-rs",
+            "# BEGIN dyndns-rs\n# END dyndns-rs\n# BEGIN dyndns-rs\n# END dyndns-rs",
+            "# BEGIN dyndns-rs\n# BEGIN dyndns-rs\n# END dyndns-rs",
+            "# BEGIN dyndns-rs\n# END dyndns-rs\n# END dyndns-rs",
+        ] {
+            assert!(HostsFile::parse(content, "dyndns-rs").is_err(), "{content}");
+        }
+    }
+
+    #[test]
+    fn ignores_blocks_of_other_markers() {
+        let content = "# BEGIN other\n# END other\n# BEGIN other\n# END other";
+        assert!(HostsFile::parse(content, "dyndns-rs").is_ok());
+    }
+}
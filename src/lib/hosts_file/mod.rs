@@ -0,0 +1,22 @@
+mod authentication_data;
+mod error;
+mod handler;
+mod hosts;
+mod record_specification;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(
+        crate::Registration::of_handler::<
+            Handler,
+            AuthenticationData,
+            RecordSpecification,
+            RecordSpecification,
+        >("HostsFile")
+        .with_max_concurrency(1),
+    );
+}
@@ -0,0 +1,5 @@
+use serde::{Deserialize, Serialize};
+
+/// Hosts files have no TTL, so there is nothing to specify per record.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {}
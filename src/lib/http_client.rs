@@ -0,0 +1,276 @@
+use std::io;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, RecordType};
+
+const DEFAULT_CONNECT_TIMEOUT: u64 = 10;
+const DEFAULT_TIMEOUT: u64 = 30;
+
+/// Settings shared by the HTTP clients of all providers.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct HttpClientConfig {
+    /// In seconds, 10 if not set.
+    pub connect_timeout: Option<u64>,
+    /// In seconds for the whole request, 30 if not set.
+    pub timeout: Option<u64>,
+    /// `dyndns-rs/<version>` if not set.
+    pub user_agent: Option<String>,
+    /// An `http://`, `https://`, `socks5://` or `socks5h://` proxy the requests to the providers
+    /// are sent through.
+    pub proxy: Option<url::Url>,
+    /// PEM files of certificate authorities trusted in addition to the built-in ones.
+    #[serde(default)]
+    pub root_certificates: Vec<PathBuf>,
+    pub min_tls_version: Option<TlsVersion>,
+    /// Default for the accounts and resolvers that are not bound themselves.
+    pub bind: Option<Binding>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum TlsVersion {
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+/// Where the connections of a client leave the host, e.g. to use the uplink whose address is
+/// published.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Binding {
+    SourceAddress(IpAddr),
+    /// Binds to the network device with `SO_BINDTODEVICE`, which is only supported on Linux.
+    Interface(String),
+}
+
+impl Binding {
+    /// Binds the connections of `builder`. Returns whether the client is limited to IPv4 or IPv6
+    /// by a source address.
+    fn apply(
+        &self,
+        builder: reqwest::ClientBuilder,
+        record_type: Option<RecordType>,
+    ) -> Result<(reqwest::ClientBuilder, Option<bool>), Error> {
+        match self {
+            Binding::SourceAddress(address) => match record_type {
+                Some(RecordType::A) if !address.is_ipv4() => Err(Error::ConfigurationError(
+                    format!("Cannot look up IPv4 addresses bound to {address}"),
+                )),
+                Some(RecordType::AAAA) if !address.is_ipv6() => Err(Error::ConfigurationError(
+                    format!("Cannot look up IPv6 addresses bound to {address}"),
+                )),
+                _ => Ok((builder.local_address(*address), Some(address.is_ipv4()))),
+            },
+            #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+            Binding::Interface(name) => Ok((
+                builder.interface(name),
+                record_type.map(|record_type| record_type == RecordType::A),
+            )),
+            #[cfg(not(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))]
+            Binding::Interface(name) => Err(Error::ConfigurationError(format!(
+                "Cannot bind to the interface {name}, which is only supported on Linux"
+            ))),
+        }
+    }
+}
+
+/// Only connects to addresses of one family, as a client bound to an address of the other
+/// family would connect to them unbound.
+struct FamilyResolver {
+    ipv4: bool,
+}
+
+impl Resolve for FamilyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let ipv4 = self.ipv4;
+        Box::pin(async move {
+            let addresses: Vec<_> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|address| address.is_ipv4() == ipv4)
+                .collect();
+            if addresses.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "{} has no {} address",
+                        name.as_str(),
+                        if ipv4 { "IPv4" } else { "IPv6" }
+                    ),
+                )
+                .into());
+            }
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Creates the HTTP clients of the providers from a [`HttpClientConfig`].
+#[derive(Clone, Default)]
+pub struct HttpClientFactory {
+    config: HttpClientConfig,
+    root_certificates: Vec<reqwest::Certificate>,
+}
+
+impl HttpClientFactory {
+    /// Reads the root certificates, so invalid ones are noticed before any client is created.
+    pub fn new(config: HttpClientConfig) -> Result<HttpClientFactory, Error> {
+        let mut root_certificates = Vec::new();
+        for path in &config.root_certificates {
+            let pem = std::fs::read(path).map_err(|e| {
+                Error::ConfigurationError(format!("Cannot read {}: {e}", path.display()))
+            })?;
+            root_certificates.extend(reqwest::Certificate::from_pem_bundle(&pem)?);
+        }
+        Ok(HttpClientFactory {
+            config,
+            root_certificates,
+        })
+    }
+
+    /// The same settings, with the connections bound as given instead.
+    pub fn with_binding(&self, binding: Binding) -> HttpClientFactory {
+        let mut factory = self.clone();
+        factory.config.bind = Some(binding);
+        factory
+    }
+
+    /// A client builder with the settings applied, for providers that need to add their own.
+    pub fn builder(&self) -> Result<reqwest::ClientBuilder, Error> {
+        self.builder_for(None)
+    }
+
+    /// Like [`HttpClientFactory::builder`], but only connecting over the address family of the
+    /// record type, if given.
+    pub fn builder_for(
+        &self,
+        record_type: Option<RecordType>,
+    ) -> Result<reqwest::ClientBuilder, Error> {
+        let config = &self.config;
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(
+                config.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT),
+            ))
+            .timeout(Duration::from_secs(
+                config.timeout.unwrap_or(DEFAULT_TIMEOUT),
+            ))
+            .user_agent(config.user_agent.clone().unwrap_or_else(|| {
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_owned()
+            }));
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.clone())?);
+        }
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(min_tls_version) = config.min_tls_version {
+            builder = builder.min_tls_version(match min_tls_version {
+                TlsVersion::Tls1_2 => reqwest::tls::Version::TLS_1_2,
+                TlsVersion::Tls1_3 => reqwest::tls::Version::TLS_1_3,
+            });
+        }
+        let ipv4 = match &config.bind {
+            Some(binding) => {
+                let ipv4;
+                (builder, ipv4) = binding.apply(builder, record_type)?;
+                ipv4
+            }
+            None => record_type.map(|record_type| record_type == RecordType::A),
+        };
+        if let Some(ipv4) = ipv4 {
+            builder = builder.dns_resolver(Arc::new(FamilyResolver { ipv4 }));
+        }
+        Ok(builder)
+    }
+
+    pub fn client(&self) -> Result<reqwest::Client, Error> {
+        Ok(self.builder()?.build()?)
+    }
+
+    pub fn client_for(&self, record_type: Option<RecordType>) -> Result<reqwest::Client, Error> {
+        Ok(self.builder_for(record_type)?.build()?)
+    }
+}
+
+/// For credentials sent in a header, so a typo in them is reported instead of panicking.
+pub fn sensitive_header_value(value: &str) -> Result<reqwest::header::HeaderValue, Error> {
+    let mut value = reqwest::header::HeaderValue::from_str(value).map_err(|e| {
+        Error::ConfigurationError(format!("Credentials are not a valid header value: {e}"))
+    })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Answers one request with the address it came from.
+    async fn echo_peer() -> SocketAddr {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, peer) = listener.accept().await.unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).await.unwrap();
+            let body = peer.ip().to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+        address
+    }
+
+    async fn request(binding: Binding) -> Result<String, Error> {
+        let address = echo_peer().await;
+        let client = HttpClientFactory::default()
+            .with_binding(binding)
+            .client_for(Some(RecordType::A))?;
+        Ok(client
+            .get(format!("http://{address}/"))
+            .send()
+            .await?
+            .text()
+            .await?)
+    }
+
+    #[tokio::test]
+    async fn binds_to_the_source_address() {
+        let source = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2));
+        let peer = request(Binding::SourceAddress(source)).await.unwrap();
+        assert_eq!(peer, "127.0.0.2");
+    }
+
+    #[test]
+    fn rejects_a_source_address_of_the_other_family() {
+        let binding = Binding::SourceAddress("::1".parse().unwrap());
+        let factory = HttpClientFactory::default().with_binding(binding);
+        assert!(factory.builder_for(Some(RecordType::A)).is_err());
+        assert!(factory.builder_for(Some(RecordType::AAAA)).is_ok());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn binds_to_the_interface() {
+        assert_eq!(
+            request(Binding::Interface("lo".to_owned())).await.unwrap(),
+            "127.0.0.1"
+        );
+        assert!(request(Binding::Interface("nonexistent0".to_owned()))
+            .await
+            .is_err());
+    }
+}
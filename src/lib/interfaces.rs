@@ -0,0 +1,20 @@
+use std::io;
+use std::net::IpAddr;
+
+/// The addresses of a network interface that reach beyond the local link.
+pub(crate) fn addresses(name: &str) -> io::Result<Vec<IpAddr>> {
+    Ok(if_addrs::get_if_addrs()?
+        .into_iter()
+        .filter(|interface| interface.name == name)
+        .map(|interface| interface.ip())
+        .filter(is_routable)
+        .collect())
+}
+
+/// Link-local addresses only reach the local network.
+pub(crate) fn is_routable(address: &IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => !address.is_link_local(),
+        IpAddr::V6(address) => address.segments()[0] & 0xffc0 != 0xfe80,
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use super::PredefinedUrl;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    pub username: String,
+    pub password: String,
+    /// Base32 secret of the account's two-factor authentication, if it is enabled.
+    pub shared_secret: Option<String>,
+    pub api_url: ApiUrl,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum ApiUrl {
+    PredefinedUrl(PredefinedUrl),
+    CustomUrl(url::Url),
+}
+
+impl From<ApiUrl> for url::Url {
+    fn from(api_url: ApiUrl) -> Self {
+        match api_url {
+            ApiUrl::PredefinedUrl(url) => url.into(),
+            ApiUrl::CustomUrl(url) => url,
+        }
+    }
+}
+
+impl From<PredefinedUrl> for ApiUrl {
+    fn from(predefined_url: PredefinedUrl) -> Self {
+        ApiUrl::PredefinedUrl(predefined_url)
+    }
+}
+
+impl From<url::Url> for ApiUrl {
+    fn from(url: url::Url) -> Self {
+        ApiUrl::CustomUrl(url)
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::header;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{totp, Error};
+
+const SESSION_COOKIE: &str = "domrobot";
+/// Returned when the session is missing or has expired.
+const AUTHENTICATION_ERROR: u32 = 2200;
+
+#[derive(Serialize, Debug)]
+struct Request<'a> {
+    method: &'a str,
+    params: &'a Value,
+}
+
+#[derive(Deserialize, Debug)]
+struct Response {
+    code: u32,
+    msg: String,
+    #[serde(rename = "resData", default)]
+    res_data: Value,
+}
+
+#[derive(Deserialize, Debug)]
+struct LoginData {
+    tfa: String,
+}
+
+/// Client for INWX's JSON-RPC API, which authenticates calls through a session cookie obtained
+/// by `account.login`.
+pub struct Domrobot {
+    http_client: reqwest::Client,
+    api_url: url::Url,
+    username: String,
+    password: String,
+    shared_secret: Option<String>,
+    session: Mutex<Option<String>>,
+    /// Number of updates using the session, which is logged out once all of them are done.
+    users: Mutex<usize>,
+    /// Held while logging in or out, so concurrent calls share one login. Keeps the time step of
+    /// the last one-time password, as INWX does not accept one twice.
+    login: tokio::sync::Mutex<Option<u64>>,
+}
+
+impl Domrobot {
+    pub fn new(
+        http_client: reqwest::Client,
+        api_url: url::Url,
+        username: &str,
+        password: &str,
+        shared_secret: Option<&str>,
+    ) -> Domrobot {
+        Domrobot {
+            http_client,
+            api_url,
+            username: username.to_owned(),
+            password: password.to_owned(),
+            shared_secret: shared_secret.map(str::to_owned),
+            session: Mutex::new(None),
+            users: Mutex::new(0),
+            login: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Calls `method`, logging in first if there is no session yet and once more if the session
+    /// has expired in the meantime.
+    pub async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, Error> {
+        let mut session = self.session();
+        if session.is_none() {
+            session = Some(self.renew_session(None).await?);
+        }
+        let res_data = match self.send(method, &params, session.as_deref()).await {
+            Err(Error::Api {
+                code: AUTHENTICATION_ERROR,
+                ..
+            }) => {
+                let session = self.renew_session(session.as_deref()).await?;
+                self.send(method, &params, Some(&session)).await?.0
+            }
+            result => result?.0,
+        };
+        Ok(serde_json::from_value(res_data)?)
+    }
+
+    /// Marks the start of an update, which has to be ended with [`Domrobot::release`].
+    pub fn acquire(&self) {
+        *self.users.lock().unwrap() += 1;
+    }
+
+    /// Marks the end of an update and logs out with `account.logout` if no other update uses the
+    /// session anymore.
+    pub async fn release(&self) -> Result<(), Error> {
+        let _login = self.login.lock().await;
+        {
+            let mut users = self.users.lock().unwrap();
+            *users -= 1;
+            if *users > 0 {
+                return Ok(());
+            }
+        }
+        let Some(session) = self.session.lock().unwrap().take() else {
+            return Ok(());
+        };
+        self.send("account.logout", &json!({}), Some(&session))
+            .await?;
+        Ok(())
+    }
+
+    fn session(&self) -> Option<String> {
+        self.session.lock().unwrap().clone()
+    }
+
+    /// Logs in, unless another call already replaced the `expired` session while waiting for
+    /// the login lock.
+    async fn renew_session(&self, expired: Option<&str>) -> Result<String, Error> {
+        let mut last_step = self.login.lock().await;
+        if let Some(session) = self
+            .session()
+            .filter(|session| Some(session.as_str()) != expired)
+        {
+            return Ok(session);
+        }
+        let session = self.login(&mut last_step).await?;
+        *self.session.lock().unwrap() = Some(session.clone());
+        Ok(session)
+    }
+
+    async fn login(&self, last_step: &mut Option<u64>) -> Result<String, Error> {
+        let (login_data, session) = self
+            .send(
+                "account.login",
+                &json!({ "user": self.username, "pass": self.password }),
+                None,
+            )
+            .await?;
+        let session = session.ok_or(Error::MissingSession)?;
+        let login_data: LoginData = serde_json::from_value(login_data)?;
+        if login_data.tfa != "0" {
+            let shared_secret = self
+                .shared_secret
+                .as_deref()
+                .ok_or(Error::MissingSharedSecret(login_data.tfa))?;
+            let mut step = totp::current_step();
+            if *last_step == Some(step) {
+                tokio::time::sleep(Duration::from_secs(totp::TIME_STEP)).await;
+                step = totp::current_step();
+            }
+            self.send(
+                "account.unlock",
+                &json!({ "tan": totp::generate(shared_secret, step)? }),
+                Some(&session),
+            )
+            .await?;
+            *last_step = Some(step);
+        }
+        Ok(session)
+    }
+
+    /// Returns the result data together with the session, if the response set one.
+    async fn send(
+        &self,
+        method: &str,
+        params: &Value,
+        session: Option<&str>,
+    ) -> Result<(Value, Option<String>), Error> {
+        let mut request = self
+            .http_client
+            .post(self.api_url.clone())
+            .json(&Request { method, params });
+        if let Some(session) = session {
+            request = request.header(header::COOKIE, format!("{SESSION_COOKIE}={session}"));
+        }
+        let response = request.send().await?;
+
+        let session = response
+            .headers()
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(|cookie| cookie.split(';').next())
+            .find_map(|cookie| cookie.strip_prefix(&format!("{SESSION_COOKIE}=")))
+            .map(str::to_owned);
+
+        let response: Response = serde_json::from_str(&response.text().await?)?;
+        if response.code >= 2000 {
+            return Err(Error::Api {
+                code: response.code,
+                message: response.msg,
+            });
+        }
+        Ok((response.res_data, session))
+    }
+}
@@ -0,0 +1,58 @@
+pub enum Error {
+    Reqwest(reqwest::Error),
+    SerdeJson(serde_json::Error),
+    /// A response with a result code outside of the 1xxx success range.
+    Api {
+        code: u32,
+        message: String,
+    },
+    InvalidSharedSecret,
+    /// Two-factor authentication of the given method is enabled for the account.
+    MissingSharedSecret(String),
+    /// `account.login` succeeded without setting the session cookie.
+    MissingSession,
+    RecordNotFound(String),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Reqwest(e) => e.fmt(f),
+            Error::SerdeJson(e) => e.fmt(f),
+            Error::Api { code, message } => write!(f, "INWX error {code}: {message}"),
+            Error::InvalidSharedSecret => write!(f, "The shared secret is not valid base32"),
+            Error::MissingSharedSecret(method) => write!(
+                f,
+                "Two-factor authentication ({method}) is enabled, but no shared secret is configured"
+            ),
+            Error::MissingSession => write!(f, "The login did not return a session"),
+            Error::RecordNotFound(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::Reqwest(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::SerdeJson(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
@@ -0,0 +1,216 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::de::IgnoredAny;
+use serde_json::json;
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::domrobot::Domrobot;
+use super::record::NameserverInfo;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+pub struct Handler {
+    domrobot: Domrobot,
+}
+
+impl Handler {
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        self.domrobot.acquire();
+        let result = self
+            .update_in_session(domain, name, ip, record_type, ttl)
+            .await;
+        // Concurrent updates share the session, so only the last one logs out.
+        let logout = self.domrobot.release().await;
+        result.and(logout)
+    }
+
+    async fn update_in_session(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        // Record names are fully qualified in INWX's API.
+        let name = match name {
+            "@" => domain.to_owned(),
+            _ => format!("{name}.{domain}"),
+        };
+        let info: NameserverInfo = self
+            .domrobot
+            .call(
+                "nameserver.info",
+                json!({ "domain": domain, "name": name, "type": record_type }),
+            )
+            .await?;
+        let record = info.record.first().ok_or_else(|| {
+            Error::RecordNotFound(format!(
+                "No {record_type} record {name:?} found in {domain}"
+            ))
+        })?;
+
+        let mut params = json!({ "id": record.id, "content": ip.to_string() });
+        if let Some(ttl) = ttl {
+            params["ttl"] = ttl.into();
+        }
+        let _: IgnoredAny = self
+            .domrobot
+            .call("nameserver.updateRecord", params)
+            .await?;
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        auth_data: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            domrobot: Domrobot::new(
+                client,
+                auth_data.api_url.clone().into(),
+                &auth_data.username,
+                &auth_data.password,
+                auth_data.shared_secret.as_deref(),
+            ),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use wiremock::matchers::{body_partial_json, header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn ok(res_data: Value) -> ResponseTemplate {
+        ResponseTemplate::new(200)
+            .set_body_json(json!({"code": 1000, "msg": "OK", "resData": res_data}))
+    }
+
+    async fn mock(server: &MockServer, rpc_method: &str, response: ResponseTemplate, calls: u64) {
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": rpc_method })))
+            .respond_with(response)
+            .expect(calls)
+            .mount(server)
+            .await;
+    }
+
+    async fn handler(server: &MockServer) -> Handler {
+        let login = ok(json!({"tfa": "0"})).insert_header("Set-Cookie", "domrobot=session; path=/");
+        mock(server, "account.login", login, 1).await;
+        Mock::given(body_partial_json(json!({"method": "account.logout"})))
+            .and(header("Cookie", "domrobot=session"))
+            .respond_with(ok(Value::Null))
+            .expect(1)
+            .mount(server)
+            .await;
+        Handler::new(
+            &AuthenticationData {
+                username: "user".to_owned(),
+                password: "secret".to_owned(),
+                shared_secret: None,
+                api_url: url::Url::parse(&server.uri()).unwrap().into(),
+            },
+            &HttpClientFactory::default(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn concurrent_updates_share_one_session() {
+        let server = MockServer::start().await;
+        let handler = handler(&server).await;
+        mock(
+            &server,
+            "nameserver.info",
+            ok(json!({"record": [{"id": 7}]})),
+            2,
+        )
+        .await;
+        Mock::given(body_partial_json(json!({
+            "method": "nameserver.updateRecord",
+            "params": {"id": 7, "content": "192.0.2.1"}
+        })))
+        .and(header("Cookie", "domrobot=session"))
+        .respond_with(ok(Value::Null))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        let (www, mail) = tokio::join!(
+            handler.update_ip_address("example.com", "www", &ip, RecordType::A, None),
+            handler.update_ip_address("example.com", "mail", &ip, RecordType::A, None),
+        );
+        www.unwrap();
+        mail.unwrap();
+    }
+
+    #[tokio::test]
+    async fn logs_out_after_a_failed_update() {
+        let server = MockServer::start().await;
+        let handler = handler(&server).await;
+        mock(&server, "nameserver.info", ok(json!({})), 1).await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        let result = handler
+            .update_ip_address("example.com", "www", &ip, RecordType::A, None)
+            .await;
+        assert!(
+            matches!(result, Err(Error::RecordNotFound(_))),
+            "{result:?}"
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+impl From<PredefinedUrl> for url::Url {
+    fn from(predefined_url: PredefinedUrl) -> Self {
+        let str = match predefined_url {
+            PredefinedUrl::OTE => "https://api.ote.domrobot.com/jsonrpc/",
+            PredefinedUrl::PRODUCTION => "https://api.domrobot.com/jsonrpc/",
+        };
+
+        url::Url::parse(str).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum PredefinedUrl {
+    OTE,
+    PRODUCTION,
+}
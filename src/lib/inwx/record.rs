@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Record {
+    pub id: u64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NameserverInfo {
+    #[serde(default)]
+    pub record: Vec<Record>,
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {
+    /// `None` keeps the record's current TTL.
+    pub ttl: Option<u32>,
+}
@@ -0,0 +1,75 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+use super::Error;
+
+pub const TIME_STEP: u64 = 30;
+const DIGITS: u32 = 6;
+
+/// The number of the current time step, which a one-time password is valid for.
+pub fn current_step() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+        / TIME_STEP
+}
+
+/// Generates the RFC 6238 one-time password of the time `step` for a base32 encoded
+/// `shared_secret`.
+pub fn generate(shared_secret: &str, step: u64) -> Result<String, Error> {
+    let key = base32::decode(
+        base32::Alphabet::RFC4648 { padding: false },
+        &shared_secret
+            .replace(' ', "")
+            .trim_end_matches('=')
+            .to_uppercase(),
+    )
+    .ok_or(Error::InvalidSharedSecret)?;
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key).map_err(|_| Error::InvalidSharedSecret)?;
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let code = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]) % 10u32.pow(DIGITS);
+    Ok(format!("{code:0width$}", width = DIGITS as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The ASCII secret `12345678901234567890` of the SHA-1 test vectors of RFC 6238.
+    const SHARED_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn generates_rfc_6238_passwords() {
+        for (time, password) in [
+            (59, "287082"),
+            (1_111_111_109, "081804"),
+            (1_234_567_890, "005924"),
+            (2_000_000_000, "279037"),
+        ] {
+            assert_eq!(generate(SHARED_SECRET, time / TIME_STEP).unwrap(), password);
+        }
+    }
+
+    #[test]
+    fn accepts_formatted_secrets() {
+        assert_eq!(
+            generate("gezd gnbv gy3t qojq gezd gnbv gy3t qojq====", 1).unwrap(),
+            "287082"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_secrets() {
+        assert!(generate("not base32!", 1).is_err());
+    }
+}
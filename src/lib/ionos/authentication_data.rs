@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// Key in the `prefix.secret` form expected by the `X-API-Key` header.
+    pub api_key: String,
+    /// Update URL of a dynamic DNS configuration, required by records using
+    /// [`super::UpdateMethod::DynamicDnsUrl`]. Created with [`super::create_dynamic_dns_url`],
+    /// e.g. by `dyndns-rs provider Ionos create-dynamic-dns-url`.
+    pub dynamic_dns_url: Option<url::Url>,
+    /// The fully qualified names the update URL was created for.
+    #[serde(default)]
+    pub dynamic_dns_domains: Vec<String>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug)]
+pub struct DynamicDnsRequest<'a> {
+    pub domains: &'a [String],
+    pub description: &'a str,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicDnsConfiguration {
+    pub update_url: url::Url,
+}
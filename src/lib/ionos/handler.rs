@@ -0,0 +1,258 @@
+use std::future::Future;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::pin::Pin;
+
+use reqwest::{Method, RequestBuilder, Response};
+use serde_json::Value;
+
+use crate::provider::{AnyAuthenticationData, ProviderCommand};
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::dynamic_dns::{DynamicDnsConfiguration, DynamicDnsRequest};
+use super::zone::{NewRecord, RecordUpdate, Zone, ZoneWithRecords};
+use super::{AuthenticationData, Error, RecordSpecification, UpdateMethod};
+
+const API_URL: &str = "https://api.hosting.ionos.com/dns/v1";
+const DYNAMIC_DNS_DESCRIPTION: &str = "dyndns-rs";
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    api_key: String,
+    dynamic_dns_url: Option<url::Url>,
+    dynamic_dns_domains: Vec<String>,
+}
+
+impl Handler {
+    fn api_request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http_client
+            .request(method, format!("{API_URL}{path}"))
+            .header("X-API-Key", &self.api_key)
+    }
+
+    async fn create_dynamic_dns_url(&self, domains: &[String]) -> Result<url::Url, Error> {
+        let response = self
+            .api_request(Method::POST, "/dyndns")
+            .json(&DynamicDnsRequest {
+                domains,
+                description: DYNAMIC_DNS_DESCRIPTION,
+            })
+            .send()
+            .await?;
+        let text = check_status(response).await?;
+        let configuration: DynamicDnsConfiguration = serde_json::from_str(&text)?;
+        Ok(configuration.update_url)
+    }
+
+    async fn update_by_dynamic_dns_url(&self, fqdn: &str, ip: &IpAddr) -> Result<(), Error> {
+        let Some(update_url) = &self.dynamic_dns_url else {
+            return Err(Error::Configuration(format!(
+                "No dynamic_dns_url configured to update {fqdn}"
+            )));
+        };
+        if !self
+            .dynamic_dns_domains
+            .iter()
+            .any(|domain| domain.eq_ignore_ascii_case(fqdn))
+        {
+            return Err(Error::Configuration(format!(
+                "{fqdn} is not one of the dynamic_dns_domains"
+            )));
+        }
+        let mut update_url = update_url.clone();
+        let parameter = match ip {
+            IpAddr::V4(_) => "ipv4",
+            IpAddr::V6(_) => "ipv6",
+        };
+        update_url
+            .query_pairs_mut()
+            .append_pair(parameter, &ip.to_string());
+        // The update URL authenticates by itself, so the API key is not sent along.
+        let response = self.http_client.get(update_url).send().await?;
+        check_status(response).await?;
+        Ok(())
+    }
+
+    async fn zone_id(&self, domain: &str) -> Result<String, Error> {
+        let response = self.api_request(Method::GET, "/zones").send().await?;
+        let text = check_status(response).await?;
+        let zones: Vec<Zone> = serde_json::from_str(&text)?;
+        zones
+            .into_iter()
+            .find(|zone| zone.name.eq_ignore_ascii_case(domain))
+            .map(|zone| zone.id)
+            .ok_or_else(|| Error::Request(format!("Zone {domain} not found")))
+    }
+
+    async fn update_by_api(
+        &self,
+        domain: &str,
+        fqdn: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let zone_id = self.zone_id(domain).await?;
+        let response = self
+            .api_request(Method::GET, &format!("/zones/{zone_id}"))
+            .query(&[
+                ("recordName", fqdn),
+                ("recordType", &record_type.to_string()),
+            ])
+            .send()
+            .await?;
+        let text = check_status(response).await?;
+        let zone: ZoneWithRecords = serde_json::from_str(&text)?;
+
+        let request = match zone.records.first() {
+            Some(record) => self
+                .api_request(
+                    Method::PUT,
+                    &format!("/zones/{zone_id}/records/{}", record.id),
+                )
+                .json(&RecordUpdate {
+                    content: ip.to_string(),
+                    ttl,
+                    disabled: false,
+                }),
+            None => self
+                .api_request(Method::POST, &format!("/zones/{zone_id}/records"))
+                .json(&[NewRecord {
+                    name: fqdn,
+                    type_: record_type,
+                    content: ip.to_string(),
+                    ttl,
+                }]),
+        };
+        check_status(request.send().await?).await?;
+        Ok(())
+    }
+
+    async fn update_ip_address(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+    ) -> Result<(), Error> {
+        let fqdn = match name {
+            "@" => domain.to_owned(),
+            _ => format!("{name}.{domain}"),
+        };
+        match specification.method {
+            UpdateMethod::Api => {
+                self.update_by_api(domain, &fqdn, ip, record_type, specification.ttl)
+                    .await
+            }
+            UpdateMethod::DynamicDnsUrl => self.update_by_dynamic_dns_url(&fqdn, ip).await,
+        }
+    }
+}
+
+/// Creates a dynamic DNS configuration for the fully qualified `domains` and returns its update
+/// URL.
+///
+/// IONOS only keeps the most recently created configuration active, so the URL is created once
+/// for all domains and stored as `dynamic_dns_url` of the [`AuthenticationData`], together with
+/// the domains as `dynamic_dns_domains`.
+pub async fn create_dynamic_dns_url(
+    auth_data: &AuthenticationData,
+    http_client_factory: &HttpClientFactory,
+    domains: &[String],
+) -> Result<url::Url, crate::Error> {
+    let handler = Handler::new(auth_data, http_client_factory)?;
+    Ok(handler.create_dynamic_dns_url(domains).await?)
+}
+
+/// `create-dynamic-dns-url <fqdn>...` creates the update URL of the given domains, see
+/// [`create_dynamic_dns_url`].
+pub const COMMANDS: &[ProviderCommand] = &[ProviderCommand {
+    name: "create-dynamic-dns-url",
+    run: run_create_dynamic_dns_url,
+}];
+
+fn run_create_dynamic_dns_url<'a>(
+    auth_data: &'a AnyAuthenticationData,
+    domains: &'a [String],
+    http_client_factory: &'a HttpClientFactory,
+) -> Pin<Box<dyn Future<Output = Result<Value, crate::Error>> + Send + 'a>> {
+    Box::pin(async move {
+        if domains.is_empty() {
+            return Err(crate::Error::ConfigurationError(
+                "Expected the fully qualified domain names to create the URL for".to_owned(),
+            ));
+        }
+        let auth_data = auth_data.downcast_ref::<AuthenticationData>()?;
+        let dynamic_dns_url =
+            create_dynamic_dns_url(auth_data, http_client_factory, domains).await?;
+        Ok(serde_json::to_value(AuthenticationData {
+            api_key: auth_data.api_key.clone(),
+            dynamic_dns_url: Some(dynamic_dns_url),
+            dynamic_dns_domains: domains.to_vec(),
+        })
+        .expect("authentication data is serializable"))
+    })
+}
+
+async fn check_status(response: Response) -> Result<String, Error> {
+    let status = response.status();
+    let text = response.text().await?;
+    if status.is_success() {
+        Ok(text)
+    } else {
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData {
+            api_key,
+            dynamic_dns_url,
+            dynamic_dns_domains,
+        }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            http_client: client,
+            api_key: api_key.clone(),
+            dynamic_dns_url: dynamic_dns_url.clone(),
+            dynamic_dns_domains: dynamic_dns_domains.clone(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(specification, domain, host, &IpAddr::V4(ip), RecordType::A)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            &specification.record_specification,
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+        )
+        .await?;
+        Ok(())
+    }
+}
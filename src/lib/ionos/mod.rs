@@ -0,0 +1,23 @@
+mod authentication_data;
+mod dynamic_dns;
+mod error;
+mod handler;
+mod record_specification;
+mod zone;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::{create_dynamic_dns_url, Handler, COMMANDS};
+pub use record_specification::{RecordSpecification, UpdateMethod};
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(
+        crate::Registration::of_handler::<
+            Handler,
+            AuthenticationData,
+            RecordSpecification,
+            RecordSpecification,
+        >("Ionos")
+        .with_commands(COMMANDS),
+    );
+}
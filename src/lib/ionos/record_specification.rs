@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum UpdateMethod {
+    /// Looks up the zone and record ids and updates the record's content.
+    Api,
+    /// Calls the update URL of the dynamic DNS configuration with the address, which sets the
+    /// records of all its domains at once.
+    DynamicDnsUrl,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {
+    pub method: UpdateMethod,
+    /// Only used by [`UpdateMethod::Api`].
+    pub ttl: Option<u32>,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::RecordType;
+
+#[derive(Deserialize, Debug)]
+pub struct Zone {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ZoneWithRecords {
+    #[serde(default)]
+    pub records: Vec<Record>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Record {
+    pub id: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct NewRecord<'a> {
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecordUpdate {
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+    pub disabled: bool,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::RecordType;
+
+#[derive(Deserialize, Debug)]
+pub struct Domain {
+    pub id: u64,
+    pub domain: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DomainRecord {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DomainRecordRequest<'a> {
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub name: &'a str,
+    pub target: String,
+    pub ttl_sec: u32,
+}
@@ -0,0 +1,157 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use reqwest::{header, Response};
+use serde::de::DeserializeOwned;
+
+use crate::http_client::sensitive_header_value;
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::domain::{Domain, DomainRecord, DomainRecordRequest};
+use super::page::Page;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_URL: &str = "https://api.linode.com/v4";
+const PAGE_SIZE: u32 = 500;
+
+pub struct Handler {
+    http_client: reqwest::Client,
+}
+
+impl Handler {
+    /// Collects the items of all pages of a list endpoint.
+    async fn list<T: DeserializeOwned>(&self, path: &str) -> Result<Vec<T>, Error> {
+        let mut items = Vec::new();
+        let mut page = 1;
+        loop {
+            let response = self
+                .http_client
+                .get(format!("{API_URL}{path}"))
+                .query(&[("page", page), ("page_size", PAGE_SIZE)])
+                .send()
+                .await?;
+            let text = check_status(response).await?;
+            let current: Page<T> = serde_json::from_str(&text)?;
+            items.extend(current.data);
+            if current.page >= current.pages {
+                return Ok(items);
+            }
+            page = current.page + 1;
+        }
+    }
+
+    async fn domain_id(&self, domain: &str) -> Result<u64, Error> {
+        let domains: Vec<Domain> = self.list("/domains").await?;
+        domains
+            .into_iter()
+            .find(|candidate| candidate.domain.eq_ignore_ascii_case(domain))
+            .map(|domain| domain.id)
+            .ok_or_else(|| Error::Request(format!("Domain {domain} not found")))
+    }
+
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: u32,
+    ) -> Result<(), Error> {
+        let name = match name {
+            "@" => "",
+            _ => name,
+        };
+        let domain_id = self.domain_id(domain).await?;
+        let records: Vec<DomainRecord> =
+            self.list(&format!("/domains/{domain_id}/records")).await?;
+        let existing = records
+            .into_iter()
+            .find(|record| record.name == name && record.type_ == record_type.to_string());
+
+        let record = DomainRecordRequest {
+            type_: record_type,
+            name,
+            target: ip.to_string(),
+            ttl_sec: ttl,
+        };
+        let request = match existing {
+            Some(DomainRecord { id, .. }) => self
+                .http_client
+                .put(format!("{API_URL}/domains/{domain_id}/records/{id}")),
+            None => self
+                .http_client
+                .post(format!("{API_URL}/domains/{domain_id}/records")),
+        };
+        let response = request.json(&record).send().await?;
+        check_status(response).await?;
+        Ok(())
+    }
+}
+
+async fn check_status(response: Response) -> Result<String, Error> {
+    let status = response.status();
+    let text = response.text().await?;
+    if status.is_success() {
+        Ok(text)
+    } else {
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { token }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let mut headers = header::HeaderMap::new();
+        let auth_value = sensitive_header_value(&format!("Bearer {token}"))?;
+        headers.insert(header::AUTHORIZATION, auth_value);
+
+        let client = http_client_factory
+            .builder()?
+            .default_headers(headers)
+            .build()?;
+
+        Ok(Handler {
+            http_client: client,
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+mod authentication_data;
+mod domain;
+mod error;
+mod handler;
+mod page;
+mod record_specification;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("Linode"));
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+/// Envelope of Linode's paginated list endpoints.
+#[derive(Deserialize, Debug)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    pub page: u32,
+    pub pages: u32,
+}
@@ -1,13 +1,44 @@
 #![feature(split_array)]
 
+pub mod azure;
+pub mod custom_http;
+pub mod desec;
+pub mod digitalocean;
+pub mod freedns;
+pub mod gandi;
 pub mod godaddy;
+pub mod google_cloud_dns;
+pub mod hosts_file;
+pub mod inwx;
+pub mod ionos;
+pub mod linode;
+pub mod namecheap;
+pub mod namecom;
+pub mod netcup;
+pub mod njalla;
+pub mod ovh;
+pub mod pihole;
+pub mod plugin;
+pub mod porkbun;
+pub mod technitium;
+pub mod vultr;
 pub mod ydns;
+pub mod zone_file;
 
+mod files;
+pub mod http_client;
+mod interfaces;
+pub mod provider;
+mod token_cache;
 mod update_handler;
+pub mod updater;
 
 pub mod error;
 pub mod record_type;
 
 pub use error::Error;
+pub use http_client::{Binding, HttpClientConfig, HttpClientFactory};
+pub use provider::{Provider, Registration, Registry};
 pub use record_type::{RecordSpecificationV6Adapter, RecordType};
 pub use update_handler::UpdateHandler;
+pub use updater::Updater;
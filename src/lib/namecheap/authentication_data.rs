@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use super::PredefinedUrl;
+
+/// Credentials for both update paths; only the ones used by the configured records are needed.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// Dynamic DNS passwords by domain, as shown on the domain's "Advanced DNS" page.
+    #[serde(default)]
+    pub dynamic_dns_passwords: HashMap<String, String>,
+    pub api: Option<ApiCredentials>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ApiCredentials {
+    pub api_user: String,
+    pub api_key: String,
+    /// Defaults to `api_user`.
+    pub user_name: Option<String>,
+    /// The whitelisted address the requests originate from.
+    pub client_ip: IpAddr,
+    pub api_url: ApiUrl,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum ApiUrl {
+    PredefinedUrl(PredefinedUrl),
+    CustomUrl(url::Url),
+}
+
+impl From<ApiUrl> for url::Url {
+    fn from(api_url: ApiUrl) -> Self {
+        match api_url {
+            ApiUrl::PredefinedUrl(url) => url.into(),
+            ApiUrl::CustomUrl(url) => url,
+        }
+    }
+}
+
+impl From<PredefinedUrl> for ApiUrl {
+    fn from(predefined_url: PredefinedUrl) -> Self {
+        ApiUrl::PredefinedUrl(predefined_url)
+    }
+}
+
+impl From<url::Url> for ApiUrl {
+    fn from(url: url::Url) -> Self {
+        ApiUrl::CustomUrl(url)
+    }
+}
@@ -0,0 +1,59 @@
+use std::net::Ipv4Addr;
+
+use roxmltree::{Document, Node};
+
+use super::Error;
+
+const UPDATE_URL: &str = "https://dynamicdns.park-your-domain.com/update";
+
+pub async fn update(
+    http_client: &reqwest::Client,
+    domain: &str,
+    host: &str,
+    password: &str,
+    ip: Ipv4Addr,
+) -> Result<(), Error> {
+    let text = http_client
+        .get(UPDATE_URL)
+        .query(&[
+            ("host", host),
+            ("domain", domain),
+            ("password", password),
+            ("ip", &ip.to_string()),
+        ])
+        .send()
+        .await?
+        .text()
+        .await?;
+    parse_response(&text)
+}
+
+/// Parses the `<interface-response>` document, which reports failures as `<Err1>`, `<Err2>`, ...
+/// children of `<errors>` instead of through the HTTP status code.
+fn parse_response(text: &str) -> Result<(), Error> {
+    let document = Document::parse(text)?;
+    let root = document.root_element();
+    let errors: Vec<String> = child(root, "errors")
+        .map(|errors| {
+            errors
+                .children()
+                .filter(Node::is_element)
+                .map(|error| error.text().unwrap_or_default().trim().to_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+    let error_count = child(root, "ErrCount")
+        .and_then(|node| node.text())
+        .and_then(|text| text.trim().parse::<u32>().ok());
+    if !errors.is_empty() || error_count.is_some_and(|count| count > 0) {
+        return Err(Error::DynamicDns(errors));
+    }
+    match child(root, "Done").and_then(|node| node.text()) {
+        Some(done) if done.trim().eq_ignore_ascii_case("true") => Ok(()),
+        _ => Err(Error::UnexpectedResponse(text.to_owned())),
+    }
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(name))
+}
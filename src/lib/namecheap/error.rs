@@ -0,0 +1,57 @@
+#[derive(Debug)]
+pub struct ApiError {
+    pub number: Option<String>,
+    pub message: String,
+}
+
+pub enum Error {
+    Reqwest(reqwest::Error),
+    Xml(roxmltree::Error),
+    /// Errors reported in the `<errors>` element of the dynamic DNS updater.
+    DynamicDns(Vec<String>),
+    /// Errors reported in the `<Errors>` element of an `ApiResponse` with `Status="ERROR"`.
+    Api(Vec<ApiError>),
+    UnexpectedResponse(String),
+    MissingCredentials(String),
+    NotSupported(&'static str),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Reqwest(e) => e.fmt(f),
+            Error::Xml(e) => e.fmt(f),
+            Error::DynamicDns(e) => e.fmt(f),
+            Error::Api(e) => e.fmt(f),
+            Error::UnexpectedResponse(e) => e.fmt(f),
+            Error::MissingCredentials(e) => e.fmt(f),
+            Error::NotSupported(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::Reqwest(err)
+    }
+}
+
+impl From<roxmltree::Error> for Error {
+    fn from(err: roxmltree::Error) -> Error {
+        Error::Xml(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
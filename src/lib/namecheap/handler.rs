@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::xml_api::XmlApi;
+use super::{dynamic_dns, AuthenticationData, Error, RecordSpecification, UpdateMethod};
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    dynamic_dns_passwords: HashMap<String, String>,
+    xml_api: Option<XmlApi>,
+}
+
+impl Handler {
+    async fn update_by_dynamic_dns(
+        &self,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), Error> {
+        let password = self.dynamic_dns_passwords.get(domain).ok_or_else(|| {
+            Error::MissingCredentials(format!("No dynamic DNS password provided for {domain}."))
+        })?;
+        dynamic_dns::update(&self.http_client, domain, host, password, ip).await
+    }
+
+    /// Reads the whole host list first, as `setHosts` drops every host that is not passed.
+    async fn update_by_set_hosts(
+        &self,
+        domain: &str,
+        host: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let xml_api = self.xml_api.as_ref().ok_or_else(|| {
+            Error::MissingCredentials("No XML API credentials provided.".to_owned())
+        })?;
+        let mut host_list = xml_api.get_hosts(domain).await?;
+        host_list.merge(host, &record_type.to_string(), ip.to_string(), ttl);
+        xml_api.set_hosts(domain, &host_list).await
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData {
+            dynamic_dns_passwords,
+            api,
+        }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            xml_api: api
+                .as_ref()
+                .map(|credentials| XmlApi::new(client.clone(), credentials.clone())),
+            http_client: client,
+            dynamic_dns_passwords: dynamic_dns_passwords.clone(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        match specification.method {
+            UpdateMethod::DynamicDns => self.update_by_dynamic_dns(domain, host, ip).await?,
+            UpdateMethod::SetHosts => {
+                self.update_by_set_hosts(
+                    domain,
+                    host,
+                    &IpAddr::V4(ip),
+                    RecordType::A,
+                    specification.ttl,
+                )
+                .await?
+            }
+        }
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        let specification = &specification.record_specification;
+        match specification.method {
+            UpdateMethod::DynamicDns => {
+                return Err(Error::NotSupported(
+                    "The dynamic DNS updater of Namecheap only supports A records.",
+                )
+                .into())
+            }
+            UpdateMethod::SetHosts => {
+                self.update_by_set_hosts(
+                    domain,
+                    host,
+                    &IpAddr::V6(ip),
+                    RecordType::AAAA,
+                    specification.ttl,
+                )
+                .await?
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+/// A single entry of a domain's host list as returned by `namecheap.domains.dns.getHosts`.
+#[derive(Debug, Clone)]
+pub struct Host {
+    pub name: String,
+    pub type_: String,
+    pub address: String,
+    pub mx_pref: Option<String>,
+    pub ttl: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct HostList {
+    pub email_type: Option<String>,
+    pub hosts: Vec<Host>,
+}
+
+impl HostList {
+    /// Replaces the address of the host with matching name and type, appending a new host if
+    /// there is none, while leaving all other hosts untouched.
+    pub fn merge(&mut self, name: &str, type_: &str, address: String, ttl: Option<u32>) {
+        let ttl = ttl.map(|ttl| ttl.to_string());
+        match self
+            .hosts
+            .iter_mut()
+            .find(|host| host.name == name && host.type_.eq_ignore_ascii_case(type_))
+        {
+            Some(host) => {
+                host.address = address;
+                if ttl.is_some() {
+                    host.ttl = ttl;
+                }
+            }
+            None => self.hosts.push(Host {
+                name: name.to_owned(),
+                type_: type_.to_owned(),
+                address,
+                mx_pref: None,
+                ttl,
+            }),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+mod authentication_data;
+mod dynamic_dns;
+mod error;
+mod handler;
+mod host;
+mod predefined_url;
+mod record_specification;
+mod xml_api;
+
+pub use authentication_data::{ApiCredentials, ApiUrl, AuthenticationData};
+pub use error::{ApiError, Error};
+pub use handler::Handler;
+pub use predefined_url::PredefinedUrl;
+pub use record_specification::{RecordSpecification, UpdateMethod};
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(
+        crate::Registration::of_handler::<
+            Handler,
+            AuthenticationData,
+            RecordSpecification,
+            RecordSpecification,
+        >("Namecheap")
+        .with_max_concurrency(1),
+    );
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+impl From<PredefinedUrl> for url::Url {
+    fn from(predefined_url: PredefinedUrl) -> Self {
+        let str = match predefined_url {
+            PredefinedUrl::SANDBOX => "https://api.sandbox.namecheap.com/xml.response",
+            PredefinedUrl::PRODUCTION => "https://api.namecheap.com/xml.response",
+        };
+
+        url::Url::parse(str).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum PredefinedUrl {
+    SANDBOX,
+    PRODUCTION,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum UpdateMethod {
+    /// The `dynamicdns.park-your-domain.com` updater, which only supports A records.
+    DynamicDns,
+    /// `namecheap.domains.dns.setHosts` of the XML API, merged into the existing host list.
+    SetHosts,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {
+    pub method: UpdateMethod,
+    /// Only used by [`UpdateMethod::SetHosts`]; defaults to the record's current TTL.
+    pub ttl: Option<u32>,
+}
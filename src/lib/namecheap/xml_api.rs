@@ -0,0 +1,133 @@
+use roxmltree::{Document, Node};
+
+use super::host::{Host, HostList};
+use super::{ApiCredentials, ApiError, Error};
+
+const GET_HOSTS: &str = "namecheap.domains.dns.getHosts";
+const SET_HOSTS: &str = "namecheap.domains.dns.setHosts";
+
+pub struct XmlApi {
+    http_client: reqwest::Client,
+    credentials: ApiCredentials,
+    api_url: url::Url,
+}
+
+impl XmlApi {
+    pub fn new(http_client: reqwest::Client, credentials: ApiCredentials) -> XmlApi {
+        let api_url = credentials.api_url.clone().into();
+        XmlApi {
+            http_client,
+            credentials,
+            api_url,
+        }
+    }
+
+    fn params(&self, command: &str, domain: &str) -> Vec<(String, String)> {
+        let (sld, tld) = domain.split_once('.').unwrap_or((domain, ""));
+        let credentials = &self.credentials;
+        vec![
+            ("ApiUser".to_owned(), credentials.api_user.clone()),
+            ("ApiKey".to_owned(), credentials.api_key.clone()),
+            (
+                "UserName".to_owned(),
+                credentials
+                    .user_name
+                    .clone()
+                    .unwrap_or_else(|| credentials.api_user.clone()),
+            ),
+            ("ClientIp".to_owned(), credentials.client_ip.to_string()),
+            ("Command".to_owned(), command.to_owned()),
+            ("SLD".to_owned(), sld.to_owned()),
+            ("TLD".to_owned(), tld.to_owned()),
+        ]
+    }
+
+    pub async fn get_hosts(&self, domain: &str) -> Result<HostList, Error> {
+        let text = self
+            .http_client
+            .get(self.api_url.clone())
+            .query(&self.params(GET_HOSTS, domain))
+            .send()
+            .await?
+            .text()
+            .await?;
+        let document = Document::parse(&text)?;
+        let result = command_result(&document, "DomainDNSGetHostsResult")?;
+        let hosts = result
+            .children()
+            .filter(|node| node.has_tag_name("host"))
+            .map(|node| Host {
+                name: attribute(node, "Name"),
+                type_: attribute(node, "Type"),
+                address: attribute(node, "Address"),
+                mx_pref: node.attribute("MXPref").map(str::to_owned),
+                ttl: node.attribute("TTL").map(str::to_owned),
+            })
+            .collect();
+        Ok(HostList {
+            email_type: result.attribute("EmailType").map(str::to_owned),
+            hosts,
+        })
+    }
+
+    /// Replaces the complete host list of `domain` with `host_list`.
+    pub async fn set_hosts(&self, domain: &str, host_list: &HostList) -> Result<(), Error> {
+        let mut params = self.params(SET_HOSTS, domain);
+        if let Some(email_type) = &host_list.email_type {
+            params.push(("EmailType".to_owned(), email_type.clone()));
+        }
+        for (index, host) in host_list.hosts.iter().enumerate() {
+            let index = index + 1;
+            params.push((format!("HostName{index}"), host.name.clone()));
+            params.push((format!("RecordType{index}"), host.type_.clone()));
+            params.push((format!("Address{index}"), host.address.clone()));
+            if let Some(mx_pref) = &host.mx_pref {
+                params.push((format!("MXPref{index}"), mx_pref.clone()));
+            }
+            if let Some(ttl) = &host.ttl {
+                params.push((format!("TTL{index}"), ttl.clone()));
+            }
+        }
+        let text = self
+            .http_client
+            .post(self.api_url.clone())
+            .form(&params)
+            .send()
+            .await?
+            .text()
+            .await?;
+        let document = Document::parse(&text)?;
+        let result = command_result(&document, "DomainDNSSetHostsResult")?;
+        match result.attribute("IsSuccess") {
+            Some(success) if success.eq_ignore_ascii_case("true") => Ok(()),
+            _ => Err(Error::UnexpectedResponse(text.clone())),
+        }
+    }
+}
+
+/// Checks the `Status` of the `ApiResponse` and returns the `result` element of its
+/// `CommandResponse`.
+fn command_result<'a, 'input>(
+    document: &'a Document<'input>,
+    result: &str,
+) -> Result<Node<'a, 'input>, Error> {
+    let root = document.root_element();
+    if root.attribute("Status") != Some("OK") {
+        let errors = root
+            .descendants()
+            .filter(|node| node.has_tag_name("Error"))
+            .map(|node| ApiError {
+                number: node.attribute("Number").map(str::to_owned),
+                message: node.text().unwrap_or_default().trim().to_owned(),
+            })
+            .collect();
+        return Err(Error::Api(errors));
+    }
+    root.descendants()
+        .find(|node| node.has_tag_name(result))
+        .ok_or_else(|| Error::UnexpectedResponse(document.input_text().to_owned()))
+}
+
+fn attribute(node: Node, name: &str) -> String {
+    node.attribute(name).unwrap_or_default().to_owned()
+}
@@ -0,0 +1,144 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use reqwest::{Method, RequestBuilder, Response};
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::record::{RecordRequest, RecordsPage};
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_URL: &str = "https://api.name.com/v4";
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    username: String,
+    token: String,
+}
+
+impl Handler {
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http_client
+            .request(method, format!("{API_URL}{path}"))
+            .basic_auth(&self.username, Some(&self.token))
+    }
+
+    async fn find_record_id(
+        &self,
+        domain: &str,
+        host: &str,
+        record_type: RecordType,
+    ) -> Result<Option<u64>, Error> {
+        let mut page = Some(1);
+        while let Some(current) = page {
+            let response = self
+                .request(Method::GET, &format!("/domains/{domain}/records"))
+                .query(&[("page", current)])
+                .send()
+                .await?;
+            let text = check_status(response).await?;
+            let records: RecordsPage = serde_json::from_str(&text)?;
+            if let Some(record) = records
+                .records
+                .iter()
+                .find(|record| record.host == host && record.type_ == record_type.to_string())
+            {
+                return Ok(Some(record.id));
+            }
+            page = records.next_page;
+        }
+        Ok(None)
+    }
+
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let host = match name {
+            "@" => "",
+            _ => name,
+        };
+        let request = match self.find_record_id(domain, host, record_type).await? {
+            Some(id) => self.request(Method::PUT, &format!("/domains/{domain}/records/{id}")),
+            None => self.request(Method::POST, &format!("/domains/{domain}/records")),
+        };
+        let response = request
+            .json(&RecordRequest {
+                host,
+                type_: record_type,
+                answer: ip.to_string(),
+                ttl,
+            })
+            .send()
+            .await?;
+        check_status(response).await?;
+        Ok(())
+    }
+}
+
+async fn check_status(response: Response) -> Result<String, Error> {
+    let status = response.status();
+    let text = response.text().await?;
+    if status.is_success() {
+        Ok(text)
+    } else {
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { username, token }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            http_client: client,
+            username: username.clone(),
+            token: token.clone(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
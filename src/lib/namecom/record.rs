@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::RecordType;
+
+#[derive(Deserialize, Debug)]
+pub struct Record {
+    pub id: u64,
+    /// Missing for records of the domain itself.
+    #[serde(default)]
+    pub host: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordsPage {
+    #[serde(default)]
+    pub records: Vec<Record>,
+    pub next_page: Option<u32>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecordRequest<'a> {
+    pub host: &'a str,
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub answer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+}
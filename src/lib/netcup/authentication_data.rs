@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    pub customer_number: String,
+    pub api_key: String,
+    pub api_password: String,
+}
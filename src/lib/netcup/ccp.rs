@@ -0,0 +1,141 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::dns_record::{DnsRecord, DnsRecordSet};
+use super::Error;
+
+const API_URL: &str = "https://ccp.netcup.net/run/webservice/servers/endpoint.php?JSON";
+
+#[derive(Serialize, Debug)]
+struct Request<'a> {
+    action: &'a str,
+    param: Value,
+}
+
+#[derive(Deserialize, Debug)]
+struct Response {
+    status: String,
+    statuscode: u32,
+    #[serde(default)]
+    shortmessage: String,
+    #[serde(default)]
+    longmessage: String,
+    #[serde(default)]
+    responsedata: Value,
+}
+
+#[derive(Deserialize, Debug)]
+struct LoginData {
+    apisessionid: String,
+}
+
+/// Client for netcup's CCP DNS API.
+pub struct Ccp {
+    http_client: reqwest::Client,
+    customer_number: String,
+    api_key: String,
+    api_password: String,
+}
+
+/// A logged in API session, which should be ended with [`Session::logout`].
+pub struct Session<'a> {
+    ccp: &'a Ccp,
+    id: String,
+}
+
+impl Ccp {
+    pub fn new(
+        http_client: reqwest::Client,
+        customer_number: &str,
+        api_key: &str,
+        api_password: &str,
+    ) -> Ccp {
+        Ccp {
+            http_client,
+            customer_number: customer_number.to_owned(),
+            api_key: api_key.to_owned(),
+            api_password: api_password.to_owned(),
+        }
+    }
+
+    pub async fn login(&self) -> Result<Session<'_>, Error> {
+        let login_data: LoginData = self
+            .call(
+                "login",
+                json!({
+                    "customernumber": self.customer_number,
+                    "apikey": self.api_key,
+                    "apipassword": self.api_password,
+                }),
+            )
+            .await?;
+        Ok(Session {
+            ccp: self,
+            id: login_data.apisessionid,
+        })
+    }
+
+    async fn call<T: DeserializeOwned>(&self, action: &str, param: Value) -> Result<T, Error> {
+        let response: Response = self
+            .http_client
+            .post(API_URL)
+            .json(&Request { action, param })
+            .send()
+            .await?
+            .json()
+            .await?;
+        if response.status != "success" {
+            return Err(Error::Api {
+                status_code: response.statuscode,
+                short_message: response.shortmessage,
+                long_message: response.longmessage,
+            });
+        }
+        Ok(serde_json::from_value(response.responsedata)?)
+    }
+}
+
+impl Session<'_> {
+    fn param(&self, mut param: Value) -> Value {
+        param["customernumber"] = self.ccp.customer_number.clone().into();
+        param["apikey"] = self.ccp.api_key.clone().into();
+        param["apisessionid"] = self.id.clone().into();
+        param
+    }
+
+    pub async fn info_dns_records(&self, domain: &str) -> Result<Vec<DnsRecord>, Error> {
+        let record_set: DnsRecordSet = self
+            .ccp
+            .call(
+                "infoDnsRecords",
+                self.param(json!({ "domainname": domain })),
+            )
+            .await?;
+        Ok(record_set.dnsrecords)
+    }
+
+    /// Only the passed records are changed or created, all others are left as they are.
+    pub async fn update_dns_records(
+        &self,
+        domain: &str,
+        dnsrecords: Vec<DnsRecord>,
+    ) -> Result<(), Error> {
+        let _: Value = self
+            .ccp
+            .call(
+                "updateDnsRecords",
+                self.param(json!({
+                    "domainname": domain,
+                    "dnsrecordset": DnsRecordSet { dnsrecords },
+                })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn logout(self) -> Result<(), Error> {
+        let _: Value = self.ccp.call("logout", self.param(json!({}))).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DnsRecord {
+    /// Missing for records that are to be created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub hostname: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub priority: String,
+    pub destination: String,
+    #[serde(default)]
+    pub deleterecord: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DnsRecordSet {
+    pub dnsrecords: Vec<DnsRecord>,
+}
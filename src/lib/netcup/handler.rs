@@ -0,0 +1,98 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::ccp::{Ccp, Session};
+use super::dns_record::DnsRecord;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+pub struct Handler {
+    ccp: Ccp,
+}
+
+impl Handler {
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+    ) -> Result<(), Error> {
+        let session = self.ccp.login().await?;
+        let result = update_in_session(&session, domain, name, ip, record_type).await;
+        let logout = session.logout().await;
+        result.and(logout)
+    }
+}
+
+async fn update_in_session(
+    session: &Session<'_>,
+    domain: &str,
+    name: &str,
+    ip: &IpAddr,
+    record_type: RecordType,
+) -> Result<(), Error> {
+    let record_type = record_type.to_string();
+    let mut records: Vec<DnsRecord> = session
+        .info_dns_records(domain)
+        .await?
+        .into_iter()
+        .filter(|record| record.hostname == name && record.type_ == record_type)
+        .collect();
+    if records.is_empty() {
+        records.push(DnsRecord {
+            id: None,
+            hostname: name.to_owned(),
+            type_: record_type,
+            priority: String::new(),
+            destination: String::new(),
+            deleterecord: false,
+        });
+    }
+    for record in &mut records {
+        record.destination = ip.to_string();
+    }
+    session.update_dns_records(domain, records).await
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData {
+            customer_number,
+            api_key,
+            api_password,
+        }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            ccp: Ccp::new(client, customer_number, api_key, api_password),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        _specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(domain, host, &IpAddr::V4(ip), RecordType::A)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(domain, host, &IpAddr::V6(ip), RecordType::AAAA)
+            .await?;
+        Ok(())
+    }
+}
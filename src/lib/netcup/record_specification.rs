@@ -0,0 +1,5 @@
+use serde::{Deserialize, Serialize};
+
+/// netcup only supports a TTL for the whole zone, so there is nothing to specify per record.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {}
@@ -0,0 +1,147 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use reqwest::header;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::http_client::sensitive_header_value;
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::record::Records;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_URL: &str = "https://njal.la/api/1/";
+
+#[derive(Serialize, Debug)]
+struct Request<'a> {
+    method: &'a str,
+    params: Value,
+}
+
+#[derive(Deserialize, Debug)]
+struct Response {
+    result: Option<Value>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+pub struct Handler {
+    http_client: reqwest::Client,
+}
+
+impl Handler {
+    async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, Error> {
+        let response: Response = self
+            .http_client
+            .post(API_URL)
+            .json(&Request { method, params })
+            .send()
+            .await?
+            .json()
+            .await?;
+        if let Some(RpcError { code, message }) = response.error {
+            return Err(Error::Rpc { code, message });
+        }
+        Ok(serde_json::from_value(response.result.unwrap_or_default())?)
+    }
+
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let Records { records } = self
+            .call("list-records", json!({ "domain": domain }))
+            .await?;
+        let existing = records
+            .into_iter()
+            .find(|record| record.name == name && record.type_ == record_type.to_string());
+
+        let (method, mut params) = match existing {
+            Some(record) => (
+                "edit-record",
+                json!({ "domain": domain, "id": record.id, "content": ip.to_string() }),
+            ),
+            None => (
+                "add-record",
+                json!({
+                    "domain": domain,
+                    "type": record_type,
+                    "name": name,
+                    "content": ip.to_string(),
+                }),
+            ),
+        };
+        if let Some(ttl) = ttl {
+            params["ttl"] = ttl.into();
+        }
+        let _: IgnoredAny = self.call(method, params).await?;
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { token }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let mut headers = header::HeaderMap::new();
+        let auth_value = sensitive_header_value(&format!("Njalla {token}"))?;
+        headers.insert(header::AUTHORIZATION, auth_value);
+
+        let client = http_client_factory
+            .builder()?
+            .default_headers(headers)
+            .build()?;
+
+        Ok(Handler {
+            http_client: client,
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Record {
+    pub id: serde_json::Value,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Records {
+    pub records: Vec<Record>,
+}
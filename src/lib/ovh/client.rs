@@ -0,0 +1,191 @@
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::{header, Method};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+
+use super::Error;
+
+/// Client for OVH's API, which requires every call to be signed with the application secret
+/// and the consumer key.
+pub struct Client {
+    api_url: String,
+    application_key: String,
+    application_secret: String,
+    consumer_key: String,
+    http_client: reqwest::Client,
+    time_offset: OnceLock<i64>,
+}
+
+impl Client {
+    pub fn new(
+        application_key: &str,
+        application_secret: &str,
+        consumer_key: &str,
+        api_url: url::Url,
+        http_client: reqwest::Client,
+    ) -> Client {
+        Client {
+            api_url: api_url.as_str().trim_end_matches('/').to_owned(),
+            application_key: application_key.to_owned(),
+            application_secret: application_secret.to_owned(),
+            consumer_key: consumer_key.to_owned(),
+            http_client,
+            time_offset: OnceLock::new(),
+        }
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        self.call(Method::GET, path, String::new()).await
+    }
+
+    pub async fn post<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &impl Serialize,
+    ) -> Result<T, Error> {
+        self.call(Method::POST, path, serde_json::to_string(body)?)
+            .await
+    }
+
+    pub async fn post_without_body<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        self.call(Method::POST, path, String::new()).await
+    }
+
+    pub async fn put<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &impl Serialize,
+    ) -> Result<T, Error> {
+        self.call(Method::PUT, path, serde_json::to_string(body)?)
+            .await
+    }
+
+    /// Difference between OVH's clock and the local one, as signatures with a timestamp too far
+    /// off are rejected.
+    async fn time_offset(&self) -> Result<i64, Error> {
+        if let Some(offset) = self.time_offset.get() {
+            return Ok(*offset);
+        }
+        let server_time: i64 = self
+            .http_client
+            .get(format!("{}/auth/time", self.api_url))
+            .send()
+            .await?
+            .json()
+            .await?;
+        let offset = server_time - unix_time();
+        Ok(*self.time_offset.get_or_init(|| offset))
+    }
+
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: String,
+    ) -> Result<T, Error> {
+        let url = format!("{}{path}", self.api_url);
+        let timestamp = (unix_time() + self.time_offset().await?).to_string();
+        let signature = signature(
+            &self.application_secret,
+            &self.consumer_key,
+            &method,
+            &url,
+            &body,
+            &timestamp,
+        );
+
+        let mut request = self
+            .http_client
+            .request(method, &url)
+            .header("X-Ovh-Application", &self.application_key)
+            .header("X-Ovh-Consumer", &self.consumer_key)
+            .header("X-Ovh-Timestamp", timestamp)
+            .header("X-Ovh-Signature", signature);
+        if !body.is_empty() {
+            request = request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(Error::Request(format!(
+                "Request failed with Code {status:?}: {text}"
+            )));
+        }
+        Ok(serde_json::from_str(&text)?)
+    }
+}
+
+/// Hashes everything the request depends on together with the secrets, in OVH's order.
+fn signature(
+    application_secret: &str,
+    consumer_key: &str,
+    method: &Method,
+    url: &str,
+    body: &str,
+    timestamp: &str,
+) -> String {
+    format!(
+        "$1${:x}",
+        Sha1::digest(
+            [
+                application_secret,
+                consumer_key,
+                method.as_str(),
+                url,
+                body,
+                timestamp,
+            ]
+            .join("+")
+        )
+    )
+}
+
+fn unix_time() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected signatures were computed independently with
+    // `printf '%s' '<secret>+<consumer key>+<method>+<url>+<body>+<timestamp>' | sha1sum`.
+
+    #[test]
+    fn signs_request_with_body() {
+        assert_eq!(
+            signature(
+                "secret",
+                "consumer",
+                &Method::PUT,
+                "https://eu.api.ovh.com/1.0/domain/zone/example.com/record/42",
+                r#"{"target":"192.0.2.1"}"#,
+                "1700000000",
+            ),
+            "$1$b47e2ed7ec9794a1517b89360e85649678a2be3d"
+        );
+    }
+
+    #[test]
+    fn signs_request_without_body() {
+        assert_eq!(
+            signature(
+                "secret",
+                "consumer",
+                &Method::GET,
+                "https://eu.api.ovh.com/1.0/domain/zone/example.com/record?fieldType=A&subDomain=www",
+                "",
+                "1700000000",
+            ),
+            "$1$91ca1a0bfd0b17aee55095a08baaf74faf90137d"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::de::IgnoredAny;
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::record::{NewRecord, RecordUpdate};
+use super::{AuthenticationData, Client, Error, RecordSpecification};
+
+pub struct Handler {
+    client: Client,
+}
+
+impl Handler {
+    async fn update_ip_address(
+        &self,
+        zone: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let sub_domain = match name {
+            "@" => "",
+            _ => name,
+        };
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("fieldType", &record_type.to_string())
+            .append_pair("subDomain", sub_domain)
+            .finish();
+        let ids: Vec<u64> = self
+            .client
+            .get(&format!("/domain/zone/{zone}/record?{query}"))
+            .await?;
+
+        match ids.first() {
+            Some(id) => {
+                let _: IgnoredAny = self
+                    .client
+                    .put(
+                        &format!("/domain/zone/{zone}/record/{id}"),
+                        &RecordUpdate {
+                            sub_domain,
+                            target: ip.to_string(),
+                            ttl,
+                        },
+                    )
+                    .await?;
+            }
+            None => {
+                let _: IgnoredAny = self
+                    .client
+                    .post(
+                        &format!("/domain/zone/{zone}/record"),
+                        &NewRecord {
+                            field_type: record_type,
+                            sub_domain,
+                            target: ip.to_string(),
+                            ttl,
+                        },
+                    )
+                    .await?;
+            }
+        }
+
+        // Changes to the zone only get published after a refresh.
+        let _: IgnoredAny = self
+            .client
+            .post_without_body(&format!("/domain/zone/{zone}/refresh"))
+            .await?;
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        auth_data: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let http_client = http_client_factory.client()?;
+
+        Ok(Handler {
+            client: Client::new(
+                &auth_data.application_key,
+                &auth_data.application_secret,
+                &auth_data.consumer_key,
+                auth_data.api_url.clone().into(),
+                http_client,
+            ),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+mod authentication_data;
+mod client;
+mod error;
+mod handler;
+mod predefined_url;
+mod record;
+mod record_specification;
+
+pub use authentication_data::{ApiUrl, AuthenticationData};
+pub use client::Client;
+pub use error::Error;
+pub use handler::Handler;
+pub use predefined_url::PredefinedUrl;
+pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("Ovh"));
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+impl From<PredefinedUrl> for url::Url {
+    fn from(predefined_url: PredefinedUrl) -> Self {
+        let str = match predefined_url {
+            PredefinedUrl::EU => "https://eu.api.ovh.com/1.0",
+            PredefinedUrl::CA => "https://ca.api.ovh.com/1.0",
+            PredefinedUrl::US => "https://api.us.ovhcloud.com/1.0",
+        };
+
+        url::Url::parse(str).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum PredefinedUrl {
+    EU,
+    CA,
+    US,
+}
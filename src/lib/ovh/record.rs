@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+use crate::RecordType;
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NewRecord<'a> {
+    pub field_type: RecordType,
+    pub sub_domain: &'a str,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordUpdate<'a> {
+    pub sub_domain: &'a str,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+}
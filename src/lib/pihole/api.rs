@@ -0,0 +1,156 @@
+use reqwest::{Method, RequestBuilder, Response};
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+
+#[derive(Serialize, Debug)]
+struct LoginRequest<'a> {
+    password: &'a str,
+}
+
+#[derive(Deserialize, Debug)]
+struct LoginResponse {
+    session: SessionData,
+}
+
+#[derive(Deserialize, Debug)]
+struct SessionData {
+    valid: bool,
+    sid: Option<String>,
+    message: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct HostsResponse {
+    config: HostsConfig,
+}
+
+#[derive(Deserialize, Debug)]
+struct HostsConfig {
+    dns: Hosts,
+}
+
+#[derive(Deserialize, Debug)]
+struct Hosts {
+    hosts: Vec<String>,
+}
+
+/// Client for the REST API of Pi-hole v6.
+pub struct Api {
+    http_client: reqwest::Client,
+    server_url: url::Url,
+    password: String,
+}
+
+/// A logged in API session, which should be ended with [`Session::logout`], as Pi-hole only
+/// allows a limited number of concurrent sessions.
+pub struct Session<'a> {
+    api: &'a Api,
+    sid: Option<String>,
+}
+
+impl Api {
+    pub fn new(http_client: reqwest::Client, server_url: url::Url, password: &str) -> Api {
+        Api {
+            http_client,
+            server_url,
+            password: password.to_owned(),
+        }
+    }
+
+    fn url(&self, path: &str) -> Result<url::Url, Error> {
+        Ok(self.server_url.join("api/")?.join(path)?)
+    }
+
+    pub async fn login(&self) -> Result<Session<'_>, Error> {
+        let response = self
+            .http_client
+            .post(self.url("auth")?)
+            .json(&LoginRequest {
+                password: &self.password,
+            })
+            .send()
+            .await?;
+        let text = check_status(response).await.map_err(|e| match e {
+            Error::Request(message) => Error::Authentication(message),
+            e => e,
+        })?;
+        let login: LoginResponse = serde_json::from_str(&text)
+            .map_err(|e| Error::Request(format!("Unexpected login response: {e}")))?;
+        if !login.session.valid {
+            return Err(Error::Authentication(
+                login.session.message.unwrap_or_default(),
+            ));
+        }
+        // Without a password set, Pi-hole hands out no session id and needs none.
+        Ok(Session {
+            api: self,
+            sid: login.session.sid,
+        })
+    }
+}
+
+impl Session<'_> {
+    fn request(&self, method: Method, url: url::Url) -> RequestBuilder {
+        let request = self.api.http_client.request(method, url);
+        match &self.sid {
+            Some(sid) => request.header("X-FTL-SID", sid),
+            None => request,
+        }
+    }
+
+    /// Returns the local DNS records, each as an `<ip> <hostname>` entry.
+    pub async fn hosts(&self) -> Result<Vec<String>, Error> {
+        let response = self
+            .request(Method::GET, self.api.url("config/dns/hosts")?)
+            .send()
+            .await?;
+        let text = check_status(response).await?;
+        let hosts: HostsResponse = serde_json::from_str(&text)
+            .map_err(|e| Error::Request(format!("Unexpected hosts response: {e}")))?;
+        Ok(hosts.config.dns.hosts)
+    }
+
+    pub async fn add_host(&self, entry: &str) -> Result<(), Error> {
+        self.change_host(Method::PUT, entry).await
+    }
+
+    pub async fn delete_host(&self, entry: &str) -> Result<(), Error> {
+        self.change_host(Method::DELETE, entry).await
+    }
+
+    async fn change_host(&self, method: Method, entry: &str) -> Result<(), Error> {
+        let mut url = self.api.url("config/dns/hosts")?;
+        // The entry contains a space, so it is appended as an encoded path segment.
+        url.path_segments_mut()
+            .map_err(|_| Error::Request(format!("Invalid server URL {}", self.api.server_url)))?
+            .push(entry);
+        check_status(self.request(method, url).send().await?).await?;
+        Ok(())
+    }
+
+    pub async fn logout(self) -> Result<(), Error> {
+        if self.sid.is_none() {
+            return Ok(());
+        }
+        check_status(
+            self.request(Method::DELETE, self.api.url("auth")?)
+                .send()
+                .await?,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+async fn check_status(response: Response) -> Result<String, Error> {
+    let status = response.status();
+    let text = response.text().await?;
+    if status.is_success() {
+        Ok(text)
+    } else {
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// Address of the Pi-hole's web interface, e.g. `http://pi.hole`.
+    pub server_url: url::Url,
+    /// The web interface password or an application password.
+    pub password: String,
+}
@@ -0,0 +1,188 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, UpdateHandler};
+
+use super::api::{Api, Session};
+use super::{AuthenticationData, Error, RecordSpecification};
+
+pub struct Handler {
+    api: Api,
+}
+
+impl Handler {
+    async fn update_ip_address(&self, domain: &str, name: &str, ip: &IpAddr) -> Result<(), Error> {
+        let hostname = match name {
+            "@" => domain.to_owned(),
+            _ => format!("{name}.{domain}"),
+        };
+        let session = self.api.login().await?;
+        let result = update_in_session(&session, &hostname, ip).await;
+        // Pi-hole only allows a few sessions at a time and keeps abandoned ones until they time
+        // out, so failed updates have to log out as well to not lock out the next run.
+        let logout = session.logout().await;
+        result.and(logout)
+    }
+}
+
+/// Replaces the entries of `hostname` with the same address family, leaving the other family's
+/// entries alone.
+async fn update_in_session(
+    session: &Session<'_>,
+    hostname: &str,
+    ip: &IpAddr,
+) -> Result<(), Error> {
+    let entry = format!("{ip} {hostname}");
+    let mut up_to_date = false;
+    for existing in session.hosts().await? {
+        let mut fields = existing.split_whitespace();
+        let Some(Ok(existing_ip)) = fields.next().map(str::parse::<IpAddr>) else {
+            continue;
+        };
+        if existing_ip.is_ipv4() != ip.is_ipv4()
+            || !fields.any(|name| name.eq_ignore_ascii_case(hostname))
+        {
+            continue;
+        }
+        if existing_ip == *ip {
+            up_to_date = true;
+        } else {
+            session.delete_host(&existing).await?;
+        }
+    }
+    if !up_to_date {
+        session.add_host(&entry).await?;
+    }
+    Ok(())
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData {
+            server_url,
+            password,
+        }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            api: Api::new(client, server_url.clone(), password),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        _specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(domain, host, &IpAddr::V4(ip))
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(domain, host, &IpAddr::V6(ip))
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    async fn handler(server: &MockServer) -> Handler {
+        Mock::given(method("POST"))
+            .and(path("/api/auth"))
+            .and(body_json(json!({"password": "password"})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"session": {"valid": true, "sid": "sid"}})),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/auth"))
+            .and(header("X-FTL-SID", "sid"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/config/dns/hosts"))
+            .and(header("X-FTL-SID", "sid"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                json!({"config": {"dns": {"hosts": [
+                    "192.0.2.1 www.example.com",
+                    "2001:db8::1 www.example.com",
+                    "192.0.2.2 mail.example.com"
+                ]}}}),
+            ))
+            .mount(server)
+            .await;
+        Handler::new(
+            &AuthenticationData {
+                server_url: url::Url::parse(&server.uri()).unwrap(),
+                password: "password".to_owned(),
+            },
+            &HttpClientFactory::default(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn replaces_the_entry_of_the_same_family() {
+        let server = MockServer::start().await;
+        let handler = handler(&server).await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/config/dns/hosts/192.0.2.1%20www.example.com"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/config/dns/hosts/198.51.100.1%20www.example.com"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1));
+        handler
+            .update_ip_address("example.com", "www", &ip)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn logs_out_after_failed_updates() {
+        let server = MockServer::start().await;
+        let handler = handler(&server).await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/config/dns/hosts/192.0.2.1%20www.example.com"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("Bad request"))
+            .mount(&server)
+            .await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1));
+        let result = handler.update_ip_address("example.com", "www", &ip).await;
+        assert!(
+            matches!(&result, Err(Error::Request(message)) if message.contains("400")),
+            "{result:?}"
+        );
+    }
+}
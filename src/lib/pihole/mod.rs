@@ -0,0 +1,23 @@
+mod api;
+mod authentication_data;
+mod error;
+mod handler;
+mod record_specification;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    // Every update logs in, while Pi-hole only allows a few sessions at a time.
+    registry.register(
+        crate::Registration::of_handler::<
+            Handler,
+            AuthenticationData,
+            RecordSpecification,
+            RecordSpecification,
+        >("PiHole")
+        .with_max_concurrency(1),
+    );
+}
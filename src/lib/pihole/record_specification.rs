@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+/// Local DNS records of Pi-hole are plain host entries without a TTL, so there is nothing to
+/// specify per record.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordSpecification {}
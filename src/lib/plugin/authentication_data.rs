@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// Program and arguments of the plugin executable.
+    pub command: Vec<String>,
+    /// Passed to the plugin with every request, e.g. its credentials.
+    #[serde(default)]
+    pub config: serde_json::Value,
+    /// In seconds the plugin has to answer every request in, 30 if not set.
+    pub timeout: Option<u64>,
+}
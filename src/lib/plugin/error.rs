@@ -0,0 +1,47 @@
+pub enum Error {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// The plugin misbehaved, e.g. by exiting or announcing an unknown protocol version.
+    Protocol(String),
+    /// An error the plugin responded with.
+    Plugin(String),
+    NotSupported(String),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => e.fmt(f),
+            Error::Json(e) => e.fmt(f),
+            Error::Protocol(e) => e.fmt(f),
+            Error::Plugin(e) => e.fmt(f),
+            Error::NotSupported(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Json(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
@@ -0,0 +1,298 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use serde::de::IgnoredAny;
+use serde_json::Value;
+use tokio::sync::Mutex;
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::process::Process;
+use super::protocol::{Capabilities, GetResult, Record, Request, PROTOCOL_VERSION};
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const DEFAULT_TIMEOUT: u64 = 30;
+
+struct Connection {
+    process: Process,
+    capabilities: Capabilities,
+}
+
+/// Drops the connection after errors that leave the plugin in an unknown state, e.g. a request
+/// that timed out and whose late response would be read as the response to the next one. The
+/// plugin is spawned again for the next request.
+fn discard_if_broken<T>(
+    connection: &mut Option<Connection>,
+    result: Result<T, Error>,
+) -> Result<T, Error> {
+    if let Err(Error::Io(_) | Error::Json(_) | Error::Protocol(_)) = result {
+        *connection = None;
+    }
+    result
+}
+
+/// Adapter forwarding updates to a plugin executable. The plugin is spawned on first use and
+/// kept running for the lifetime of the handler.
+pub struct Handler {
+    command: Vec<String>,
+    config: Value,
+    timeout: Duration,
+    connection: Mutex<Option<Connection>>,
+}
+
+impl Handler {
+    /// Connects to the plugin if not done yet and returns its capabilities.
+    pub async fn capabilities(&self) -> Result<Capabilities, Error> {
+        let mut connection = self.connection.lock().await;
+        let result = self
+            .connect(&mut connection)
+            .await
+            .map(|connection| connection.capabilities.clone());
+        discard_if_broken(&mut connection, result)
+    }
+
+    async fn connect<'a>(
+        &self,
+        connection: &'a mut Option<Connection>,
+    ) -> Result<&'a mut Connection, Error> {
+        if connection.is_none() {
+            let mut process = Process::spawn(&self.command, self.timeout)?;
+            let capabilities: Capabilities = process
+                .call(&Request::Capabilities {
+                    config: &self.config,
+                })
+                .await?;
+            if capabilities.protocol_version != PROTOCOL_VERSION {
+                return Err(Error::Protocol(format!(
+                    "The plugin speaks protocol version {}, expected {PROTOCOL_VERSION}.",
+                    capabilities.protocol_version
+                )));
+            }
+            *connection = Some(Connection {
+                process,
+                capabilities,
+            });
+        }
+        Ok(connection.as_mut().unwrap())
+    }
+
+    /// Removes a record, if the plugin supports it.
+    pub async fn delete_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        record_type: RecordType,
+    ) -> Result<(), Error> {
+        let mut connection = self.connection.lock().await;
+        let result = self
+            .send_delete(&mut connection, specification, domain, host, record_type)
+            .await;
+        discard_if_broken(&mut connection, result)
+    }
+
+    async fn send_delete(
+        &self,
+        connection: &mut Option<Connection>,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        record_type: RecordType,
+    ) -> Result<(), Error> {
+        let connection = self.connect(connection).await?;
+        if !connection.capabilities.delete {
+            return Err(Error::NotSupported(
+                "The plugin does not support deleting records.".to_owned(),
+            ));
+        }
+        let _: IgnoredAny = connection
+            .process
+            .call(&Request::Delete {
+                config: &self.config,
+                record: Record {
+                    domain,
+                    host,
+                    type_: record_type,
+                },
+                specification: &specification.0,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ip_address(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: IpAddr,
+        record_type: RecordType,
+    ) -> Result<(), Error> {
+        let mut connection = self.connection.lock().await;
+        let result = self
+            .send_update(
+                &mut connection,
+                specification,
+                domain,
+                host,
+                ip,
+                record_type,
+            )
+            .await;
+        discard_if_broken(&mut connection, result)
+    }
+
+    async fn send_update(
+        &self,
+        connection: &mut Option<Connection>,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: IpAddr,
+        record_type: RecordType,
+    ) -> Result<(), Error> {
+        let connection = self.connect(connection).await?;
+        if !connection.capabilities.record_types.contains(&record_type) {
+            return Err(Error::NotSupported(format!(
+                "The plugin does not support {record_type} records."
+            )));
+        }
+        let record = Record {
+            domain,
+            host,
+            type_: record_type,
+        };
+
+        if connection.capabilities.get {
+            let current: GetResult = connection
+                .process
+                .call(&Request::Get {
+                    config: &self.config,
+                    record,
+                })
+                .await?;
+            if current.ips == [ip] {
+                return Ok(());
+            }
+        }
+        let _: IgnoredAny = connection
+            .process
+            .call(&Request::Update {
+                config: &self.config,
+                record,
+                ip,
+                specification: &specification.0,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData {
+            command,
+            config,
+            timeout,
+        }: &AuthenticationData,
+        _http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        Ok(Handler {
+            command: command.clone(),
+            config: config.clone(),
+            timeout: Duration::from_secs(timeout.unwrap_or(DEFAULT_TIMEOUT)),
+            connection: Mutex::new(None),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(specification, domain, host, IpAddr::V4(ip), RecordType::A)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            &specification.record_specification,
+            domain,
+            host,
+            IpAddr::V6(ip),
+            RecordType::AAAA,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    /// A plugin that hangs on the first update and succeeds on the next ones, which it records by
+    /// creating `marker`.
+    fn plugin(marker: &std::path::Path) -> Handler {
+        let script = r#"
+            while read -r line; do
+                case "$line" in
+                    *'"capabilities"'*) echo '{"result":{"protocol_version":1,"record_types":["A"],"delete":true}}' ;;
+                    *'"update"'*) [ -e "$1" ] || { touch "$1"; exec sleep 60; }; echo '{"result":null}' ;;
+                    *'"delete"'*) echo '{"error":{"message":"No such record"}}' ;;
+                esac
+            done
+        "#;
+        Handler::new(
+            &AuthenticationData {
+                command: vec![
+                    "sh".to_owned(),
+                    "-c".to_owned(),
+                    script.to_owned(),
+                    "sh".to_owned(),
+                    marker.display().to_string(),
+                ],
+                config: Value::Null,
+                timeout: Some(1),
+            },
+            &HttpClientFactory::default(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn restarts_the_plugin_after_a_timeout() {
+        let marker = std::env::temp_dir().join(format!("dyndns-plugin-{}", std::process::id()));
+        let _ = std::fs::remove_file(&marker);
+        let handler = plugin(&marker);
+        let specification = RecordSpecification(Value::Null);
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+
+        let result = handler
+            .update_ip_address(&specification, "example.com", "www", ip, RecordType::A)
+            .await;
+        assert!(matches!(result, Err(Error::Protocol(_))), "{result:?}");
+        assert!(handler.connection.lock().await.is_none());
+
+        handler
+            .update_ip_address(&specification, "example.com", "www", ip, RecordType::A)
+            .await
+            .unwrap();
+        let result = handler
+            .delete_record(&specification, "example.com", "www", RecordType::A)
+            .await;
+        assert!(matches!(result, Err(Error::Plugin(_))), "{result:?}");
+        assert!(handler.connection.lock().await.is_some());
+        let _ = std::fs::remove_file(&marker);
+    }
+}
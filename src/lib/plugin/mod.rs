@@ -0,0 +1,21 @@
+mod authentication_data;
+mod error;
+mod handler;
+mod process;
+mod protocol;
+mod record_specification;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use protocol::{Capabilities, PROTOCOL_VERSION};
+pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        RecordSpecification,
+        RecordSpecification,
+    >("Plugin"));
+}
@@ -0,0 +1,77 @@
+use std::process::Stdio;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+
+use super::protocol::{Request, Response};
+use super::Error;
+
+/// A running plugin, which is killed once dropped.
+pub struct Process {
+    _child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    timeout: Duration,
+}
+
+impl Process {
+    pub fn spawn(command: &[String], timeout: Duration) -> Result<Process, Error> {
+        let (program, args) = command
+            .split_first()
+            .ok_or_else(|| Error::Protocol("No plugin command provided.".to_owned()))?;
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true)
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(Error::Protocol(format!("Could not attach to {program}.")));
+        };
+        Ok(Process {
+            _child: child,
+            stdin,
+            stdout: BufReader::new(stdout),
+            timeout,
+        })
+    }
+
+    /// Sends the request and waits for the response. After an [`Error::Io`], [`Error::Json`] or
+    /// [`Error::Protocol`], including a timeout, the process is out of step with the plugin and
+    /// must not be used anymore.
+    pub async fn call<T: DeserializeOwned>(&mut self, request: &Request<'_>) -> Result<T, Error> {
+        let timeout = self.timeout;
+        tokio::time::timeout(timeout, self.exchange(request))
+            .await
+            .map_err(|_| {
+                Error::Protocol(format!(
+                    "The plugin did not respond within {} seconds.",
+                    timeout.as_secs()
+                ))
+            })?
+    }
+
+    async fn exchange<T: DeserializeOwned>(&mut self, request: &Request<'_>) -> Result<T, Error> {
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        self.stdin.write_all(line.as_bytes()).await?;
+        self.stdin.flush().await?;
+
+        line.clear();
+        if self.stdout.read_line(&mut line).await? == 0 {
+            return Err(Error::Protocol(
+                "The plugin exited without responding.".to_owned(),
+            ));
+        }
+        let response: Response = serde_json::from_str(&line)?;
+        match response {
+            Response {
+                error: Some(error), ..
+            } => Err(Error::Plugin(error.message)),
+            Response { result, .. } => Ok(serde_json::from_value(result.unwrap_or_default())?),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Messages exchanged with a plugin. Every request is written to the plugin's stdin as a single
+//! line of JSON, e.g.
+//!
+//! ```json
+//! {"method":"update","params":{"config":{},"record":{"domain":"example.com","host":"www","type":"A"},"ip":"192.0.2.1","specification":{}}}
+//! ```
+//!
+//! and answered by a single line on its stdout, either `{"result":...}` or
+//! `{"error":{"message":"..."}}`. Anything the plugin writes to stderr is passed through.
+//!
+//! * `capabilities` is sent first and answered with [`Capabilities`].
+//! * `get` is answered with `{"ips":[...]}`, the addresses the record currently points to.
+//! * `update` points the record to `ip`, its result is ignored.
+//! * `delete` removes the record, its result is ignored.
+
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::RecordType;
+
+/// Version of the protocol, plugins announcing another one are rejected.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum Request<'a> {
+    Capabilities {
+        config: &'a Value,
+    },
+    Get {
+        config: &'a Value,
+        record: Record<'a>,
+    },
+    Update {
+        config: &'a Value,
+        record: Record<'a>,
+        ip: IpAddr,
+        specification: &'a Value,
+    },
+    Delete {
+        config: &'a Value,
+        record: Record<'a>,
+        specification: &'a Value,
+    },
+}
+
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct Record<'a> {
+    pub domain: &'a str,
+    pub host: &'a str,
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Response {
+    pub result: Option<Value>,
+    pub error: Option<ResponseError>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ResponseError {
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Capabilities {
+    pub protocol_version: u32,
+    pub record_types: Vec<RecordType>,
+    /// Whether `get` is supported, which allows skipping updates of up to date records.
+    #[serde(default)]
+    pub get: bool,
+    #[serde(default)]
+    pub delete: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetResult {
+    pub ips: Vec<IpAddr>,
+}
@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+/// Passed through to the plugin as is, its structure is up to the plugin.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(transparent)]
+pub struct RecordSpecification(pub serde_json::Value);
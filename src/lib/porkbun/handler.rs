@@ -0,0 +1,149 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::Serialize;
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::request::{CreateRecord, Credentials, EditRecord};
+use super::response::{Response, Status};
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_URL: &str = "https://api.porkbun.com/api/json/v3";
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    api_key: String,
+    api_secret: String,
+}
+
+impl Handler {
+    fn credentials(&self) -> Credentials {
+        Credentials {
+            apikey: &self.api_key,
+            secretapikey: &self.api_secret,
+        }
+    }
+
+    /// Porkbun reports failures in the `status` field of the body, so it is checked in addition
+    /// to the HTTP status code.
+    async fn call(&self, path: &str, body: &impl Serialize) -> Result<Response, Error> {
+        let response = self
+            .http_client
+            .post(format!("{API_URL}{path}"))
+            .json(body)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        let response: Response = serde_json::from_str(&text).map_err(|e| {
+            if status.is_success() {
+                Error::SerdeJson(e)
+            } else {
+                Error::Api(format!("Request failed with Code {status:?}: {text}"))
+            }
+        })?;
+        match response.status {
+            Status::Success => Ok(response),
+            Status::Error => Err(Error::Api(response.message.unwrap_or(text))),
+        }
+    }
+
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let subdomain = match name {
+            "@" => "",
+            _ => name,
+        };
+        let existing = self
+            .call(
+                &format!("/dns/retrieveByNameType/{domain}/{record_type}/{subdomain}"),
+                &self.credentials(),
+            )
+            .await?;
+        if existing.records.is_empty() {
+            self.call(
+                &format!("/dns/create/{domain}"),
+                &CreateRecord {
+                    credentials: self.credentials(),
+                    name: subdomain,
+                    type_: record_type,
+                    content: ip.to_string(),
+                    ttl,
+                },
+            )
+            .await?;
+        } else {
+            self.call(
+                &format!("/dns/editByNameType/{domain}/{record_type}/{subdomain}"),
+                &EditRecord {
+                    credentials: self.credentials(),
+                    content: ip.to_string(),
+                    ttl,
+                },
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData {
+            api_key,
+            api_secret,
+        }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            http_client: client,
+            api_key: api_key.clone(),
+            api_secret: api_secret.clone(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
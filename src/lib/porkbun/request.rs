@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::RecordType;
+
+#[derive(Serialize, Debug)]
+pub struct Credentials<'a> {
+    pub apikey: &'a str,
+    pub secretapikey: &'a str,
+}
+
+#[derive(Serialize, Debug)]
+pub struct EditRecord<'a> {
+    #[serde(flatten)]
+    pub credentials: Credentials<'a>,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CreateRecord<'a> {
+    #[serde(flatten)]
+    pub credentials: Credentials<'a>,
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+}
@@ -0,0 +1,18 @@
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Success,
+    Error,
+}
+
+/// Porkbun answers every call with a `status` field and, on failure, a `message`.
+#[derive(Deserialize, Debug)]
+pub struct Response {
+    pub status: Status,
+    pub message: Option<String>,
+    #[serde(default)]
+    pub records: Vec<IgnoredAny>,
+}
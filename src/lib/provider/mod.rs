@@ -0,0 +1,9 @@
+pub use provider::{HandlerProvider, Provider};
+pub use registry::{
+    AnyAuthenticationData, AnySpecification, CreateProvider, ProviderCommand, Registration,
+    Registry, RunCommand,
+};
+
+#[allow(clippy::module_inception)]
+mod provider;
+mod registry;
@@ -0,0 +1,82 @@
+use std::marker::PhantomData;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use async_trait::async_trait;
+
+use crate::{Error, RecordSpecificationV6Adapter, UpdateHandler};
+
+use super::AnySpecification;
+
+/// Object safe counterpart of [`UpdateHandler`], which takes the record specifications as
+/// produced by the provider's [`super::Registration`].
+#[async_trait]
+pub trait Provider: Send + Sync {
+    async fn update_ipv4_record(
+        &self,
+        specification: &AnySpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), Error>;
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &AnySpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), Error>;
+}
+
+/// Makes an [`UpdateHandler`] usable as a [`Provider`].
+pub struct HandlerProvider<Handler, AuthenticationData, SpecificationV4, SpecificationV6> {
+    handler: Handler,
+    _types: PhantomData<fn(AuthenticationData, SpecificationV4, SpecificationV6)>,
+}
+
+impl<Handler, AuthenticationData, SpecificationV4, SpecificationV6>
+    HandlerProvider<Handler, AuthenticationData, SpecificationV4, SpecificationV6>
+{
+    pub fn new(handler: Handler) -> Self {
+        HandlerProvider {
+            handler,
+            _types: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Handler, AuthenticationData, SpecificationV4, SpecificationV6> Provider
+    for HandlerProvider<Handler, AuthenticationData, SpecificationV4, SpecificationV6>
+where
+    Handler: UpdateHandler<AuthenticationData, SpecificationV4, SpecificationV6> + Send + Sync,
+    SpecificationV4: Sync + 'static,
+    SpecificationV6: Sync + 'static,
+{
+    async fn update_ipv4_record(
+        &self,
+        specification: &AnySpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), Error> {
+        let specification = specification.downcast_ref::<SpecificationV4>()?;
+        self.handler
+            .update_ipv4_record(specification, domain, host, ip)
+            .await
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &AnySpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), Error> {
+        let specification =
+            specification.downcast_ref::<RecordSpecificationV6Adapter<SpecificationV6>>()?;
+        self.handler
+            .update_ipv6_record(specification, domain, host, ip)
+            .await
+    }
+}
@@ -0,0 +1,194 @@
+use std::any::{type_name, Any};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{Error, HttpClientFactory, RecordSpecificationV6Adapter, UpdateHandler};
+
+use super::{HandlerProvider, Provider};
+
+/// Authentication data of a provider, as deserialized by its [`Registration`].
+pub struct AnyAuthenticationData(Box<dyn Any + Send + Sync>);
+
+/// A record specification of a provider, as deserialized by its [`Registration`]. IPv6
+/// specifications include their [`RecordSpecificationV6Adapter`].
+pub struct AnySpecification(Box<dyn Any + Send + Sync>);
+
+impl AnyAuthenticationData {
+    pub fn downcast_ref<T: 'static>(&self) -> Result<&T, Error> {
+        self.0.downcast_ref().ok_or_else(|| mismatch::<T>())
+    }
+}
+
+impl AnySpecification {
+    pub fn downcast_ref<T: 'static>(&self) -> Result<&T, Error> {
+        self.0.downcast_ref().ok_or_else(|| mismatch::<T>())
+    }
+}
+
+fn mismatch<T>() -> Error {
+    Error::ConfigurationError(format!(
+        "Expected {}, the data belongs to another provider",
+        type_name::<T>()
+    ))
+}
+
+pub type CreateProvider =
+    fn(&AnyAuthenticationData, &HttpClientFactory) -> Result<Box<dyn Provider>, Error>;
+
+/// Runs a [`ProviderCommand`] with the authentication data of an account and the arguments given
+/// to the command, and returns the authentication data to store instead.
+pub type RunCommand = for<'a> fn(
+    &'a AnyAuthenticationData,
+    &'a [String],
+    &'a HttpClientFactory,
+)
+    -> Pin<Box<dyn Future<Output = Result<Value, Error>> + Send + 'a>>;
+
+/// A command of a provider besides updating records, e.g. to create credentials that have to be
+/// stored in the authentication data. Run with `dyndns-rs provider <provider> <command>`.
+#[derive(Clone, Copy)]
+pub struct ProviderCommand {
+    pub name: &'static str,
+    pub run: RunCommand,
+}
+
+/// How to read the configuration of a provider and create it.
+#[derive(Clone, Copy)]
+pub struct Registration {
+    pub name: &'static str,
+    pub deserialize_authentication_data: fn(Value) -> Result<AnyAuthenticationData, Error>,
+    pub deserialize_specification_v4: fn(Value) -> Result<AnySpecification, Error>,
+    pub deserialize_specification_v6: fn(Value) -> Result<AnySpecification, Error>,
+    pub create: CreateProvider,
+    /// Maximum number of records of the provider updated at the same time, for providers that
+    /// rewrite a whole zone or file per update.
+    pub max_concurrency: Option<usize>,
+    pub commands: &'static [ProviderCommand],
+}
+
+impl Registration {
+    /// Registration of a provider implemented as [`UpdateHandler`].
+    pub fn of_handler<Handler, AuthenticationData, SpecificationV4, SpecificationV6>(
+        name: &'static str,
+    ) -> Registration
+    where
+        Handler: UpdateHandler<AuthenticationData, SpecificationV4, SpecificationV6>
+            + Send
+            + Sync
+            + 'static,
+        AuthenticationData: DeserializeOwned + Send + Sync + 'static,
+        SpecificationV4: DeserializeOwned + Send + Sync + 'static,
+        SpecificationV6: DeserializeOwned + Send + Sync + 'static,
+    {
+        Registration {
+            name,
+            deserialize_authentication_data: |value| {
+                Ok(AnyAuthenticationData(Box::new(deserialize::<
+                    AuthenticationData,
+                >(value)?)))
+            },
+            deserialize_specification_v4: |value| {
+                Ok(AnySpecification(Box::new(deserialize::<SpecificationV4>(
+                    value,
+                )?)))
+            },
+            deserialize_specification_v6: |value| {
+                Ok(AnySpecification(Box::new(deserialize::<
+                    RecordSpecificationV6Adapter<SpecificationV6>,
+                >(value)?)))
+            },
+            create: |auth_data, http_client_factory| {
+                let handler = Handler::new(
+                    auth_data.downcast_ref::<AuthenticationData>()?,
+                    http_client_factory,
+                )?;
+                Ok(Box::new(HandlerProvider::<
+                    Handler,
+                    AuthenticationData,
+                    SpecificationV4,
+                    SpecificationV6,
+                >::new(handler)))
+            },
+            max_concurrency: None,
+            commands: &[],
+        }
+    }
+
+    pub fn with_max_concurrency(self, max_concurrency: usize) -> Registration {
+        Registration {
+            max_concurrency: Some(max_concurrency),
+            ..self
+        }
+    }
+
+    pub fn with_commands(self, commands: &'static [ProviderCommand]) -> Registration {
+        Registration { commands, ..self }
+    }
+
+    pub fn command(&self, name: &str) -> Result<&'static ProviderCommand, Error> {
+        self.commands
+            .iter()
+            .find(|command| command.name == name)
+            .ok_or_else(|| {
+                Error::ConfigurationError(format!("Unknown command {name} of {}", self.name))
+            })
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(value: Value) -> Result<T, Error> {
+    serde_json::from_value(value)
+        .map_err(|e| Error::ConfigurationError(format!("Invalid {}: {e}", type_name::<T>())))
+}
+
+/// The providers available by their name, which is the key used in the configuration.
+#[derive(Default)]
+pub struct Registry {
+    registrations: HashMap<&'static str, Registration>,
+}
+
+impl Registry {
+    /// A registry containing every provider of this crate.
+    pub fn with_builtin_providers() -> Registry {
+        let mut registry = Registry::default();
+        crate::godaddy::register(&mut registry);
+        crate::ydns::register(&mut registry);
+        crate::desec::register(&mut registry);
+        crate::digitalocean::register(&mut registry);
+        crate::gandi::register(&mut registry);
+        crate::porkbun::register(&mut registry);
+        crate::namecheap::register(&mut registry);
+        crate::ovh::register(&mut registry);
+        crate::google_cloud_dns::register(&mut registry);
+        crate::azure::register(&mut registry);
+        crate::linode::register(&mut registry);
+        crate::vultr::register(&mut registry);
+        crate::inwx::register(&mut registry);
+        crate::netcup::register(&mut registry);
+        crate::ionos::register(&mut registry);
+        crate::freedns::register(&mut registry);
+        crate::njalla::register(&mut registry);
+        crate::namecom::register(&mut registry);
+        crate::technitium::register(&mut registry);
+        crate::pihole::register(&mut registry);
+        crate::hosts_file::register(&mut registry);
+        crate::zone_file::register(&mut registry);
+        crate::custom_http::register(&mut registry);
+        crate::plugin::register(&mut registry);
+        registry
+    }
+
+    /// Adds a provider, replacing any registered under the same name.
+    pub fn register(&mut self, registration: Registration) {
+        self.registrations.insert(registration.name, registration);
+    }
+
+    pub fn get(&self, name: &str) -> Result<&Registration, Error> {
+        self.registrations
+            .get(name)
+            .ok_or_else(|| Error::ConfigurationError(format!("Unknown provider {name}")))
+    }
+}
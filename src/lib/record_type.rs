@@ -14,3 +14,20 @@ pub struct RecordSpecificationV6Adapter<RecordSpecificationV6> {
     pub record_specification: RecordSpecificationV6,
     pub custom_interface_id: Option<Ipv6Addr>,
 }
+
+impl<RecordSpecificationV6> RecordSpecificationV6Adapter<RecordSpecificationV6> {
+    pub fn apply_custom_interface_id(&self, ip: Ipv6Addr) -> Ipv6Addr {
+        match self.custom_interface_id {
+            Some(interface_id) => replace_interface_id(ip, interface_id),
+            None => ip,
+        }
+    }
+}
+
+fn replace_interface_id(ip: Ipv6Addr, interface_id: Ipv6Addr) -> Ipv6Addr {
+    let interface_id = interface_id.segments();
+    let suffix = interface_id.rsplit_array_ref::<4>().1;
+    let mut ipv6_segments = ip.segments();
+    ipv6_segments[4..].copy_from_slice(suffix);
+    Ipv6Addr::from(ipv6_segments)
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// Address of the DNS server's web service, e.g. `http://192.168.0.2:5380`.
+    pub server_url: url::Url,
+    /// An API token created in the server's web console.
+    pub token: String,
+}
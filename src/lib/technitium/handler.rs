@@ -0,0 +1,235 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::record::{Records, Response};
+use super::{AuthenticationData, Error, RecordSpecification};
+
+pub struct Handler {
+    http_client: reqwest::Client,
+    server_url: url::Url,
+    token: String,
+}
+
+impl Handler {
+    async fn call<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Option<T>, Error> {
+        let response: Response<T> = self
+            .http_client
+            .get(self.server_url.join(path)?)
+            .query(&[("token", self.token.as_str())])
+            .query(params)
+            .send()
+            .await?
+            .json()
+            .await?;
+        if response.status != "ok" {
+            return Err(Error::Api {
+                status: response.status,
+                message: response.error_message.unwrap_or_default(),
+            });
+        }
+        Ok(response.response)
+    }
+
+    async fn update_ip_address(
+        &self,
+        zone: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let fqdn = match name {
+            "@" => zone.to_owned(),
+            _ => format!("{name}.{zone}"),
+        };
+        let records: Option<Records> = self
+            .call(
+                "api/zones/records/get",
+                &[("domain", &fqdn), ("zone", zone)],
+            )
+            .await?;
+        let current = records
+            .map(|records| records.records)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|record| {
+                record.name.eq_ignore_ascii_case(&fqdn) && record.type_ == record_type.to_string()
+            })
+            .and_then(|record| record.r_data.ip_address);
+
+        let record_type = record_type.to_string();
+        let ip = ip.to_string();
+        let ttl = ttl.map(|ttl| ttl.to_string());
+        let mut params = vec![
+            ("domain", fqdn.as_str()),
+            ("zone", zone),
+            ("type", &record_type),
+        ];
+        if let Some(ttl) = &ttl {
+            params.push(("ttl", ttl));
+        }
+        let _: Option<IgnoredAny> = match current {
+            Some(current) => {
+                let current = current.to_string();
+                params.extend([("value", current.as_str()), ("newValue", &ip)]);
+                self.call("api/zones/records/update", &params).await?
+            }
+            None => {
+                params.push(("ipAddress", &ip));
+                self.call("api/zones/records/add", &params).await?
+            }
+        };
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { server_url, token }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
+
+        Ok(Handler {
+            http_client: client,
+            server_url: server_url.clone(),
+            token: token.clone(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    async fn handler(server: &MockServer) -> Handler {
+        Mock::given(method("GET"))
+            .and(path("/api/zones/records/get"))
+            .and(query_param("token", "token"))
+            .and(query_param("domain", "www.example.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {"records": [
+                    {"name": "www.example.com", "type": "A", "rData": {"ipAddress": "192.0.2.1"}},
+                    {"name": "www.example.com", "type": "TXT", "rData": {"text": "txt"}}
+                ]}
+            })))
+            .mount(server)
+            .await;
+        Handler::new(
+            &AuthenticationData {
+                server_url: url::Url::parse(&server.uri()).unwrap(),
+                token: "token".to_owned(),
+            },
+            &HttpClientFactory::default(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn updates_existing_and_adds_missing_records() {
+        let server = MockServer::start().await;
+        let handler = handler(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/api/zones/records/update"))
+            .and(query_param("type", "A"))
+            .and(query_param("value", "192.0.2.1"))
+            .and(query_param("newValue", "198.51.100.1"))
+            .and(query_param("ttl", "300"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"status": "ok"})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/zones/records/add"))
+            .and(query_param("type", "AAAA"))
+            .and(query_param("ipAddress", "2001:db8::1"))
+            .and(query_param_is_missing("ttl"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"status": "ok"})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1));
+        handler
+            .update_ip_address("example.com", "www", &ip, RecordType::A, Some(300))
+            .await
+            .unwrap();
+        let ip = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+        handler
+            .update_ip_address("example.com", "www", &ip, RecordType::AAAA, None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn reports_api_errors() {
+        let server = MockServer::start().await;
+        let handler = handler(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/api/zones/records/update"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "invalid-token",
+                "errorMessage": "Invalid token or session expired."
+            })))
+            .mount(&server)
+            .await;
+
+        let ip = IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1));
+        let result = handler
+            .update_ip_address("example.com", "www", &ip, RecordType::A, None)
+            .await;
+        assert!(
+            matches!(&result, Err(Error::Api { status, .. }) if status == "invalid-token"),
+            "{result:?}"
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use std::net::IpAddr;
+
+use serde::Deserialize;
+
+/// Technitium wraps every result in a `status` envelope.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Response<T> {
+    pub status: String,
+    pub error_message: Option<String>,
+    pub response: Option<T>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Records {
+    #[serde(default)]
+    pub records: Vec<Record>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Record {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub r_data: RecordData,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordData {
+    pub ip_address: Option<IpAddr>,
+}
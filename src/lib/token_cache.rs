@@ -0,0 +1,55 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+/// Tokens are renewed this long before they expire, so they don't run out mid-request.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// The answer of an OAuth2 token endpoint.
+#[derive(Deserialize, Debug)]
+pub(crate) struct TokenResponse {
+    pub access_token: String,
+    /// In seconds.
+    pub expires_in: u64,
+}
+
+struct AccessToken {
+    token: String,
+    expires_at: Instant,
+}
+
+/// Keeps the OAuth2 access token of a provider until shortly before it expires.
+#[derive(Default)]
+pub(crate) struct TokenCache {
+    /// Held while a new token is requested, so concurrent updates wait for it instead of each
+    /// requesting their own.
+    access_token: Mutex<Option<AccessToken>>,
+}
+
+impl TokenCache {
+    /// The cached token, or the one `request` results in if it is missing or about to expire.
+    pub async fn get_or_request<E>(
+        &self,
+        request: impl Future<Output = Result<TokenResponse, E>>,
+    ) -> Result<String, E> {
+        let mut access_token = self.access_token.lock().await;
+        if let Some(access_token) = access_token
+            .as_ref()
+            .filter(|access_token| access_token.expires_at > Instant::now() + EXPIRY_MARGIN)
+        {
+            return Ok(access_token.token.clone());
+        }
+
+        let TokenResponse {
+            access_token: token,
+            expires_in,
+        } = request.await?;
+        *access_token = Some(AccessToken {
+            token: token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(expires_in),
+        });
+        Ok(token)
+    }
+}
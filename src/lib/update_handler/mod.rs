@@ -1,3 +1,4 @@
 pub use update_handler::UpdateHandler;
 
+#[allow(clippy::module_inception)]
 mod update_handler;
@@ -1,21 +1,29 @@
+use std::future::Future;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
-use crate::{Error, RecordSpecificationV6Adapter};
+use crate::{Error, HttpClientFactory, RecordSpecificationV6Adapter};
 
+/// Implementations may use `async fn`, as long as the futures are `Send`, so updates can run on
+/// any thread of a multi-threaded runtime.
 pub trait UpdateHandler<AuthenticationData, RecordSpecificationV4, RecordSpecificationV6> {
-    fn new(auth_data: &AuthenticationData) -> Self;
-    async fn update_ipv4_record(
+    fn new(
+        auth_data: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, Error>
+    where
+        Self: Sized;
+    fn update_ipv4_record(
         &self,
         specification: &RecordSpecificationV4,
         domain: &str,
         host: &str,
         ip: Ipv4Addr,
-    ) -> Result<(), Error>;
-    async fn update_ipv6_record(
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn update_ipv6_record(
         &self,
         specification: &RecordSpecificationV6Adapter<RecordSpecificationV6>,
         domain: &str,
         host: &str,
         ip: Ipv6Addr,
-    ) -> Result<(), Error>;
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 }
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use crate::provider::{AnyAuthenticationData, AnySpecification};
+use crate::{Binding, Error, Registry};
+
+use super::{AuthenticationDataList, DomainSpecifications};
+
+/// Domains of a service together with the name of their account.
+pub type AccountDomains = (Option<String>, Vec<DomainSpecifications<AnySpecification>>);
+
+/// Authentication data of every service by account name, where `None` is the account of the
+/// domains that do not reference one.
+pub struct Accounts {
+    accounts: HashMap<(String, Option<String>), AccountData>,
+}
+
+struct AccountData {
+    authentication_data: AnyAuthenticationData,
+    bind: Option<Binding>,
+}
+
+impl Accounts {
+    pub fn new(
+        authentication_data_list: AuthenticationDataList,
+        registry: &Registry,
+    ) -> Result<Accounts, Error> {
+        let mut accounts = HashMap::new();
+        for account in authentication_data_list {
+            let description = account.name.as_deref().unwrap_or("without a name");
+            let mut authentication_data = account.authentication_data.into_iter();
+            let (Some((service, value)), None) =
+                (authentication_data.next(), authentication_data.next())
+            else {
+                return Err(Error::ConfigurationError(format!(
+                    "Expected the authentication data of exactly one service for the account \
+                     {description}"
+                )));
+            };
+            let registration = registry.get(&service)?;
+            let description = describe(&service, account.name.as_deref());
+            let authentication_data = (registration.deserialize_authentication_data)(value)?;
+            let account_data = AccountData {
+                authentication_data,
+                bind: account.bind,
+            };
+            if accounts
+                .insert((service, account.name), account_data)
+                .is_some()
+            {
+                return Err(Error::AuthenticationError(format!(
+                    "Duplicate authentication data for {description}."
+                )));
+            }
+        }
+        Ok(Accounts { accounts })
+    }
+
+    pub fn get(&self, service: &str, name: Option<&str>) -> Result<&AnyAuthenticationData, Error> {
+        Ok(&self.account(service, name)?.authentication_data)
+    }
+
+    /// The binding of the account's requests, if it has its own.
+    pub fn binding(&self, service: &str, name: Option<&str>) -> Result<Option<&Binding>, Error> {
+        Ok(self.account(service, name)?.bind.as_ref())
+    }
+
+    fn account(&self, service: &str, name: Option<&str>) -> Result<&AccountData, Error> {
+        self.accounts
+            .get(&(service.to_owned(), name.map(str::to_owned)))
+            .ok_or_else(|| {
+                Error::AuthenticationError(format!(
+                    "No authentication data provided for {}.",
+                    describe(service, name)
+                ))
+            })
+    }
+
+    /// Groups the domains of a service by the account they reference, keeping their order. Fails
+    /// if an account does not exist.
+    pub fn group(
+        &self,
+        service: &str,
+        specifications: Vec<DomainSpecifications<AnySpecification>>,
+    ) -> Result<Vec<AccountDomains>, Error> {
+        let mut groups: Vec<(Option<String>, Vec<_>)> = Vec::new();
+        for domain in specifications {
+            match groups.iter_mut().find(|(name, _)| *name == domain.account) {
+                Some((_, domains)) => domains.push(domain),
+                None => groups.push((domain.account.clone(), vec![domain])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(name, domains)| {
+                self.get(service, name.as_deref())?;
+                Ok((name, domains))
+            })
+            .collect()
+    }
+}
+
+fn describe(service: &str, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("account {name} of {service}"),
+        None => service.to_owned(),
+    }
+}
@@ -0,0 +1,167 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use tokio::process::Command;
+
+use crate::RecordType;
+
+/// Where the addresses of a host come from, if not from the resolver of the updater.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressSource {
+    /// One of the named resolvers of the options, e.g. one bound to another uplink.
+    Resolver(String),
+    /// The address of a local interface, e.g. of a WAN interface with a public address.
+    Interface(String),
+    /// Fixed addresses, e.g. of a host outside the network.
+    Static {
+        #[serde(default)]
+        ipv4: Option<Ipv4Addr>,
+        #[serde(default)]
+        ipv6: Option<Ipv6Addr>,
+    },
+    /// A host in the local network, found by its MAC address in the neighbour table. Kept in
+    /// lower case and separated by colons, like `ip neighbour` shows it.
+    Neighbour(#[serde(deserialize_with = "deserialize_mac")] String),
+}
+
+impl AddressSource {
+    /// Identifies the source in the state of the last run.
+    pub fn key(&self) -> String {
+        match self {
+            AddressSource::Resolver(name) => format!("resolver {name}"),
+            AddressSource::Interface(name) => format!("interface {name}"),
+            AddressSource::Static { ipv4, ipv6 } => {
+                let addresses: Vec<String> = (ipv4.iter().map(ToString::to_string))
+                    .chain(ipv6.iter().map(ToString::to_string))
+                    .collect();
+                format!("static {}", addresses.join(" "))
+            }
+            AddressSource::Neighbour(mac) => format!("neighbour {mac}"),
+        }
+    }
+}
+
+/// Accepts the usual notations, e.g. `AA-BB-CC-DD-EE-FF`.
+fn deserialize_mac<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let mac = String::deserialize(deserializer)?;
+    let normalized = mac.to_lowercase().replace('-', ":");
+    let octets: Vec<&str> = normalized.split(':').collect();
+    if octets.len() != 6
+        || !octets
+            .iter()
+            .all(|octet| octet.len() == 2 && octet.chars().all(|c| c.is_ascii_hexdigit()))
+    {
+        return Err(de::Error::custom(format!("{mac} is not a MAC address")));
+    }
+    Ok(normalized)
+}
+
+/// The address of the record type's family, preferring global IPv6 addresses over unique local
+/// ones.
+pub fn select(addresses: &[IpAddr], record_type: RecordType) -> Option<IpAddr> {
+    let candidates: Vec<IpAddr> = addresses
+        .iter()
+        .copied()
+        .filter(|address| match record_type {
+            RecordType::A => address.is_ipv4(),
+            RecordType::AAAA => address.is_ipv6(),
+        })
+        .collect();
+    candidates
+        .iter()
+        .find(|address| match address {
+            IpAddr::V4(_) => true,
+            IpAddr::V6(address) => address.segments()[0] & 0xfe00 != 0xfc00,
+        })
+        .or(candidates.first())
+        .copied()
+}
+
+/// Reads the neighbour table with `ip neighbour`, as there is no portable way to access it.
+/// Entries without a link layer address, e.g. failed ones, are ignored.
+pub async fn neighbour_addresses(mac: &str) -> Result<Vec<IpAddr>, String> {
+    let output = Command::new("ip")
+        .args(["neighbour", "show"])
+        .output()
+        .await
+        .map_err(|e| format!("Cannot run ip neighbour: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "ip neighbour failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let mut addresses = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let lladdr = fields
+            .iter()
+            .position(|field| *field == "lladdr")
+            .and_then(|index| fields.get(index + 1));
+        if !lladdr.is_some_and(|lladdr| *lladdr == mac) {
+            continue;
+        }
+        if let Some(Ok(address)) = fields.first().map(|field| field.parse::<IpAddr>()) {
+            if crate::interfaces::is_routable(&address) {
+                addresses.push(address);
+            }
+        }
+    }
+    Ok(addresses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(addresses: &[&str]) -> Vec<IpAddr> {
+        addresses
+            .iter()
+            .map(|address| address.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn selects_family_of_record_type() {
+        let addresses = parse(&["2001:db8::1", "192.0.2.1", "192.0.2.2"]);
+        assert_eq!(
+            select(&addresses, RecordType::A),
+            Some("192.0.2.1".parse().unwrap())
+        );
+        assert_eq!(
+            select(&addresses, RecordType::AAAA),
+            Some("2001:db8::1".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn prefers_global_over_unique_local_addresses() {
+        let addresses = parse(&["fd00::1", "fc00::1", "2001:db8::1"]);
+        assert_eq!(
+            select(&addresses, RecordType::AAAA),
+            Some("2001:db8::1".parse().unwrap())
+        );
+        let addresses = parse(&["fd00::1", "192.0.2.1"]);
+        assert_eq!(
+            select(&addresses, RecordType::AAAA),
+            Some("fd00::1".parse().unwrap())
+        );
+        assert_eq!(select(&addresses[1..], RecordType::AAAA), None);
+    }
+
+    #[test]
+    fn normalizes_neighbour_mac() {
+        let source: AddressSource =
+            serde_json::from_str(r#"{"neighbour": "AA-BB-CC-0D-EE-FF"}"#).unwrap();
+        assert_eq!(
+            source,
+            AddressSource::Neighbour("aa:bb:cc:0d:ee:ff".to_owned())
+        );
+        assert!(serde_json::from_str::<AddressSource>(r#"{"neighbour": "aa:bb:cc"}"#).is_err());
+        assert!(
+            serde_json::from_str::<AddressSource>(r#"{"neighbour": "aa:bb:cc:dd:ee:gg"}"#).is_err()
+        );
+    }
+}
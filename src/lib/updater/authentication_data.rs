@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Binding;
+
+pub type AuthenticationDataList = Vec<Account>;
+
+/// An entry of the authentication list, e.g. `{"name": "customer", "GoDaddy": {...}}`, where the
+/// authentication data is left to the provider to deserialize. Domains reference named accounts
+/// by their `account`, entries without a name are used by the domains that reference none.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Account {
+    pub name: Option<String>,
+    /// Binding of the requests to the provider, the one of the HTTP client options if not set.
+    pub bind: Option<Binding>,
+    #[serde(flatten)]
+    pub authentication_data: HashMap<String, Value>,
+}
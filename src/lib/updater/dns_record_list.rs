@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::provider::AnySpecification;
+use crate::{Error, Registration, Registry};
+
+use super::AddressSource;
+
+/// Every entry maps the names of providers to their domains, e.g. `{"GoDaddy": [...]}`. The
+/// record specifications are left to the providers to deserialize.
+pub type DnsRecordList = Vec<HashMap<String, Vec<DomainSpecifications<Value>>>>;
+
+/// The domains of a provider, with the record specifications deserialized by the provider.
+pub struct ServiceSpecifications {
+    pub registration: Registration,
+    pub domains: Vec<DomainSpecifications<AnySpecification>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DomainSpecifications<Specification> {
+    pub domain_name: String,
+    /// Name of the account the domain is managed by, the unnamed one if not set.
+    #[serde(default)]
+    pub account: Option<String>,
+    pub specifications: Vec<HostSpecifications<Specification>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HostSpecifications<Specification> {
+    pub host_name: String,
+    pub ipv4: Option<Specification>,
+    /// Includes the `custom_interface_id` next to the `record_specification`.
+    pub ipv6: Option<Specification>,
+    /// The addresses of the updater's resolver if not set.
+    #[serde(default)]
+    pub address_source: Option<AddressSource>,
+}
+
+pub fn resolve(
+    dns_record_list: DnsRecordList,
+    registry: &Registry,
+) -> Result<Vec<ServiceSpecifications>, Error> {
+    let mut services = Vec::new();
+    for (name, domains) in dns_record_list.into_iter().flatten() {
+        let registration = *registry.get(&name)?;
+        services.push(ServiceSpecifications {
+            registration,
+            domains: domains
+                .into_iter()
+                .map(|domain| domain.resolve(&registration))
+                .collect::<Result<_, _>>()?,
+        });
+    }
+    Ok(services)
+}
+
+impl DomainSpecifications<Value> {
+    fn resolve(
+        self,
+        registration: &Registration,
+    ) -> Result<DomainSpecifications<AnySpecification>, Error> {
+        Ok(DomainSpecifications {
+            domain_name: self.domain_name,
+            account: self.account,
+            specifications: self
+                .specifications
+                .into_iter()
+                .map(|host| host.resolve(registration))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl HostSpecifications<Value> {
+    fn resolve(
+        self,
+        registration: &Registration,
+    ) -> Result<HostSpecifications<AnySpecification>, Error> {
+        Ok(HostSpecifications {
+            host_name: self.host_name,
+            ipv4: self
+                .ipv4
+                .map(registration.deserialize_specification_v4)
+                .transpose()?,
+            ipv6: self
+                .ipv6
+                .map(registration.deserialize_specification_v6)
+                .transpose()?,
+            address_source: self.address_source,
+        })
+    }
+}
@@ -0,0 +1,23 @@
+mod accounts;
+mod address_source;
+mod authentication_data;
+mod dns_record_list;
+mod ips;
+mod report;
+mod resolver;
+mod retry_handler;
+mod state_store;
+#[allow(clippy::module_inception)]
+mod updater;
+
+pub use accounts::Accounts;
+pub use address_source::AddressSource;
+pub use authentication_data::{Account, AuthenticationDataList};
+pub use dns_record_list::{
+    resolve, DnsRecordList, DomainSpecifications, HostSpecifications, ServiceSpecifications,
+};
+pub use ips::IPs;
+pub use report::{Outcome, RecordReport, Report};
+pub use resolver::{HttpResolver, HttpResolverConfig, PublicIpResolver, Resolver};
+pub use state_store::{MemoryStateStore, PreferencesStateStore, StateStore};
+pub use updater::{Options, Updater, UpdaterBuilder};
@@ -0,0 +1,58 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{Error, RecordType};
+
+/// Result of [`super::Updater::run_once`].
+#[derive(Debug)]
+pub struct Report {
+    /// The addresses of the updater's resolver, if any host uses them.
+    pub ipv4: Option<Ipv4Addr>,
+    pub ipv6: Option<Ipv6Addr>,
+    pub records: Vec<RecordReport>,
+}
+
+#[derive(Debug)]
+pub struct RecordReport {
+    pub provider: &'static str,
+    pub account: Option<String>,
+    pub domain: String,
+    pub host: String,
+    pub record_type: RecordType,
+    /// `None` if the address of the host could not be resolved.
+    pub ip: Option<IpAddr>,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Updated,
+    /// Skipped, as the address did not change since the last successful run.
+    Unchanged,
+    Failed(Error),
+}
+
+impl Report {
+    pub fn failures(&self) -> impl Iterator<Item = &RecordReport> {
+        self.records
+            .iter()
+            .filter(|record| matches!(record.outcome, Outcome::Failed(_)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Returns the error of the first failed record, if any.
+    pub fn into_result(self) -> Result<(), Error> {
+        match self
+            .records
+            .into_iter()
+            .find_map(|record| match record.outcome {
+                Outcome::Failed(error) => Some(error),
+                _ => None,
+            }) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
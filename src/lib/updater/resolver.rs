@@ -0,0 +1,156 @@
+use std::future::Future;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use futures_retry::FutureRetry;
+use serde::{Deserialize, Serialize};
+
+use crate::{Binding, HttpClientFactory, RecordType};
+
+use super::retry_handler::RetryHandler;
+
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+const DEFAULT_BASE_DELAY: u64 = 100;
+const DEFAULT_IPV4_URL: &str = "https://api.ipify.org";
+const DEFAULT_IPV6_URL: &str = "https://api6.ipify.org";
+
+/// Determines the addresses the records are pointed to.
+#[async_trait]
+pub trait Resolver: Send + Sync {
+    async fn ipv4(&self) -> Option<Ipv4Addr>;
+
+    async fn ipv6(&self) -> Option<Ipv6Addr>;
+}
+
+/// Asks public services for the addresses, retrying with an exponential backoff.
+pub struct PublicIpResolver {
+    max_attempts: usize,
+    base_delay: u64,
+}
+
+impl PublicIpResolver {
+    /// `base_delay` is in milliseconds and raised to the power of the failed attempts.
+    pub fn new(max_attempts: usize, base_delay: u64) -> Self {
+        PublicIpResolver {
+            max_attempts,
+            base_delay,
+        }
+    }
+}
+
+impl Default for PublicIpResolver {
+    fn default() -> Self {
+        PublicIpResolver::new(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY)
+    }
+}
+
+#[async_trait]
+impl Resolver for PublicIpResolver {
+    async fn ipv4(&self) -> Option<Ipv4Addr> {
+        retry(self.max_attempts, self.base_delay, public_ip::addr_v4).await
+    }
+
+    async fn ipv6(&self) -> Option<Ipv6Addr> {
+        retry(self.max_attempts, self.base_delay, public_ip::addr_v6).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct HttpResolverConfig {
+    /// A service answering with the plain IPv4 address, `https://api.ipify.org` if not set.
+    pub ipv4_url: Option<url::Url>,
+    /// A service answering with the plain IPv6 address, `https://api6.ipify.org` if not set.
+    pub ipv6_url: Option<url::Url>,
+    /// Binding of the lookups, the one of the HTTP client options if not set.
+    pub bind: Option<Binding>,
+}
+
+/// Asks a web service answering with the plain address with the shared HTTP client settings,
+/// so the lookups can be bound to the uplink whose address is published. IPv4 lookups only
+/// connect over IPv4 and IPv6 lookups only over IPv6, and neither goes through the proxy, which
+/// would answer with its own address.
+pub struct HttpResolver {
+    http_client_factory: HttpClientFactory,
+    config: HttpResolverConfig,
+    max_attempts: usize,
+    base_delay: u64,
+}
+
+impl HttpResolver {
+    pub fn new(http_client_factory: &HttpClientFactory, config: HttpResolverConfig) -> Self {
+        let http_client_factory = match &config.bind {
+            Some(binding) => http_client_factory.with_binding(binding.clone()),
+            None => http_client_factory.clone(),
+        };
+        HttpResolver {
+            http_client_factory,
+            config,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_BASE_DELAY,
+        }
+    }
+
+    async fn resolve<Addr: FromStr + Send>(
+        &self,
+        record_type: RecordType,
+        url: &str,
+    ) -> Option<Addr> {
+        let client = self
+            .http_client_factory
+            .builder_for(Some(record_type))
+            .ok()?
+            .no_proxy()
+            .build()
+            .ok()?;
+        let client = &client;
+        retry(self.max_attempts, self.base_delay, || async move {
+            let response = client.get(url).send().await.ok()?;
+            let text = response.error_for_status().ok()?.text().await.ok()?;
+            text.trim().parse().ok()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl Resolver for HttpResolver {
+    async fn ipv4(&self) -> Option<Ipv4Addr> {
+        let url = self.config.ipv4_url.as_ref();
+        self.resolve(
+            RecordType::A,
+            url.map_or(DEFAULT_IPV4_URL, url::Url::as_str),
+        )
+        .await
+    }
+
+    async fn ipv6(&self) -> Option<Ipv6Addr> {
+        let url = self.config.ipv6_url.as_ref();
+        self.resolve(
+            RecordType::AAAA,
+            url.map_or(DEFAULT_IPV6_URL, url::Url::as_str),
+        )
+        .await
+    }
+}
+
+/// `base_delay` is in milliseconds and raised to the power of the failed attempts.
+async fn retry<Resolve, AddrFuture, AddrType>(
+    max_attempts: usize,
+    base_delay: u64,
+    resolve: Resolve,
+) -> Option<AddrType>
+where
+    Resolve: Fn() -> AddrFuture + Copy + Send,
+    AddrFuture: Future<Output = Option<AddrType>> + Send,
+    AddrType: Send,
+{
+    let ip = FutureRetry::new(
+        || async move { resolve().await.ok_or(()) },
+        RetryHandler::new(max_attempts, base_delay),
+    )
+    .await
+    .ok()?
+    .0;
+    Some(ip)
+}
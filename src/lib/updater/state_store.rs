@@ -0,0 +1,59 @@
+use std::sync::Mutex;
+
+use preferences::{AppInfo, Preferences};
+
+use crate::Error;
+
+use super::IPs;
+
+/// Remembers the addresses of the last successful run, so records are only updated once the
+/// addresses change.
+pub trait StateStore: Send + Sync {
+    /// Returns `None` if there is no state, or it cannot be read.
+    fn load(&self) -> Option<IPs>;
+
+    fn save(&self, ips: &IPs) -> Result<(), Error>;
+}
+
+/// Keeps the state in the preferences directory of the application.
+pub struct PreferencesStateStore {
+    app_info: &'static AppInfo,
+    key: String,
+}
+
+impl PreferencesStateStore {
+    pub fn new(app_info: &'static AppInfo, key: &str) -> Self {
+        PreferencesStateStore {
+            app_info,
+            key: key.to_owned(),
+        }
+    }
+}
+
+impl StateStore for PreferencesStateStore {
+    fn load(&self) -> Option<IPs> {
+        IPs::load(self.app_info, &self.key).ok()
+    }
+
+    fn save(&self, ips: &IPs) -> Result<(), Error> {
+        ips.save(self.app_info, &self.key)?;
+        Ok(())
+    }
+}
+
+/// Keeps the state for the lifetime of the store only.
+#[derive(Default)]
+pub struct MemoryStateStore {
+    ips: Mutex<Option<IPs>>,
+}
+
+impl StateStore for MemoryStateStore {
+    fn load(&self) -> Option<IPs> {
+        self.ips.lock().unwrap().clone()
+    }
+
+    fn save(&self, ips: &IPs) -> Result<(), Error> {
+        *self.ips.lock().unwrap() = Some(ips.clone());
+        Ok(())
+    }
+}
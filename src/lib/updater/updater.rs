@@ -0,0 +1,937 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::provider::{AnySpecification, Provider};
+use crate::{Error, HttpClientConfig, HttpClientFactory, RecordType, Registration, Registry};
+
+use super::address_source::{self, AddressSource};
+use super::dns_record_list::{self, DnsRecordList, DomainSpecifications, HostSpecifications};
+use super::{
+    Accounts, AuthenticationDataList, HttpResolver, HttpResolverConfig, IPs, Outcome,
+    PublicIpResolver, RecordReport, Report, Resolver, StateStore,
+};
+
+const DEFAULT_MAX_CONCURRENCY: usize = 8;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Options {
+    /// Updates every record, even if the addresses did not change since the last run.
+    #[serde(default)]
+    pub force: bool,
+    /// Maximum number of records updated at the same time.
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: usize,
+    /// Maximum number of records updated at the same time per provider, by provider name.
+    /// Providers may impose a lower limit themselves.
+    #[serde(default)]
+    pub provider_concurrency: HashMap<String, usize>,
+    /// Timeouts, proxy and TLS settings of the requests to the providers.
+    #[serde(default)]
+    pub http_client: HttpClientConfig,
+    /// Looks the addresses up with an [`HttpResolver`] instead of a [`PublicIpResolver`], e.g.
+    /// to bind the lookups to an uplink.
+    pub resolver: Option<HttpResolverConfig>,
+    /// Resolvers hosts can use as their address source, by name.
+    #[serde(default)]
+    pub resolvers: HashMap<String, HttpResolverConfig>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            force: false,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            provider_concurrency: HashMap::new(),
+            http_client: HttpClientConfig::default(),
+            resolver: None,
+            resolvers: HashMap::new(),
+        }
+    }
+}
+
+fn default_max_concurrency() -> usize {
+    DEFAULT_MAX_CONCURRENCY
+}
+
+/// Keeps the records of a set of providers pointed to the current addresses.
+pub struct Updater {
+    accounts: Accounts,
+    services: Vec<Arc<AccountService>>,
+    resolver: Box<dyn Resolver>,
+    resolvers: HashMap<String, Box<dyn Resolver>>,
+    state_store: Option<Box<dyn StateStore>>,
+    http_client_factory: HttpClientFactory,
+    options: Options,
+}
+
+/// The address of every source and record type the hosts use, where `None` is the updater's
+/// resolver. Failed lookups keep their error message, as it is reported for every record.
+type Addresses<'a> = HashMap<(Option<&'a AddressSource>, RecordType), Result<IpAddr, String>>;
+
+/// The domains a provider manages with one account.
+struct AccountService {
+    registration: Registration,
+    account: Option<String>,
+    domains: Vec<DomainSpecifications<AnySpecification>>,
+}
+
+#[derive(Default)]
+pub struct UpdaterBuilder {
+    records: DnsRecordList,
+    credentials: AuthenticationDataList,
+    registry: Option<Registry>,
+    resolver: Option<Box<dyn Resolver>>,
+    resolvers: HashMap<String, Box<dyn Resolver>>,
+    state_store: Option<Box<dyn StateStore>>,
+    options: Options,
+}
+
+impl UpdaterBuilder {
+    pub fn records(mut self, records: DnsRecordList) -> Self {
+        self.records = records;
+        self
+    }
+
+    pub fn credentials(mut self, credentials: AuthenticationDataList) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// The providers the records and credentials may refer to, those of this crate if not set.
+    pub fn registry(mut self, registry: Registry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Depends on [`Options::resolver`] if not set.
+    pub fn resolver(mut self, resolver: impl Resolver + 'static) -> Self {
+        self.resolver = Some(Box::new(resolver));
+        self
+    }
+
+    /// A resolver hosts can use as their address source, replacing the one of the options with
+    /// the same name.
+    pub fn named_resolver(mut self, name: &str, resolver: impl Resolver + 'static) -> Self {
+        self.resolvers.insert(name.to_owned(), Box::new(resolver));
+        self
+    }
+
+    /// Without a state store every run updates all records.
+    pub fn state_store(mut self, state_store: impl StateStore + 'static) -> Self {
+        self.state_store = Some(Box::new(state_store));
+        self
+    }
+
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Deserializes the records and credentials, failing if any of them is invalid or a record
+    /// references a missing account or resolver.
+    pub fn build(self) -> Result<Updater, Error> {
+        let registry = self
+            .registry
+            .unwrap_or_else(Registry::with_builtin_providers);
+        let accounts = Accounts::new(self.credentials, &registry)?;
+        let mut services = Vec::new();
+        for service in dns_record_list::resolve(self.records, &registry)? {
+            for (account, domains) in accounts.group(service.registration.name, service.domains)? {
+                services.push(Arc::new(AccountService {
+                    registration: service.registration,
+                    account,
+                    domains,
+                }));
+            }
+        }
+
+        let http_client_factory = HttpClientFactory::new(self.options.http_client.clone())?;
+        let resolver = match (self.resolver, &self.options.resolver) {
+            (Some(resolver), _) => resolver,
+            (None, Some(config)) => {
+                Box::new(HttpResolver::new(&http_client_factory, config.clone()))
+            }
+            (None, None) => Box::<PublicIpResolver>::default(),
+        };
+        let mut resolvers: HashMap<String, Box<dyn Resolver>> = HashMap::new();
+        for (name, config) in &self.options.resolvers {
+            resolvers.insert(
+                name.clone(),
+                Box::new(HttpResolver::new(&http_client_factory, config.clone())),
+            );
+        }
+        resolvers.extend(self.resolvers);
+        for service in &services {
+            for domain in &service.domains {
+                for host in &domain.specifications {
+                    if let Some(AddressSource::Resolver(name)) = &host.address_source {
+                        if !resolvers.contains_key(name) {
+                            return Err(Error::ConfigurationError(format!(
+                                "Unknown resolver {name} for {} in {}",
+                                host.host_name, domain.domain_name
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Updater {
+            accounts,
+            services,
+            resolver,
+            resolvers,
+            state_store: self.state_store,
+            http_client_factory,
+            options: self.options,
+        })
+    }
+}
+
+impl Updater {
+    pub fn builder() -> UpdaterBuilder {
+        UpdaterBuilder::default()
+    }
+
+    fn hosts(&self) -> impl Iterator<Item = &HostSpecifications<AnySpecification>> {
+        self.services
+            .iter()
+            .flat_map(|service| &service.domains)
+            .flat_map(|domain| &domain.specifications)
+    }
+
+    /// Looks up the addresses of every source the hosts use, each once.
+    async fn resolve_addresses(&self) -> Addresses<'_> {
+        // The record types every source is needed for.
+        let mut sources: Vec<(Option<&AddressSource>, Vec<RecordType>)> = Vec::new();
+        for host in self.hosts() {
+            let source = host.address_source.as_ref();
+            let index = match sources.iter().position(|(known, _)| *known == source) {
+                Some(index) => index,
+                None => {
+                    sources.push((source, Vec::new()));
+                    sources.len() - 1
+                }
+            };
+            for record_type in [RecordType::A, RecordType::AAAA] {
+                if has_record(host, record_type) && !sources[index].1.contains(&record_type) {
+                    sources[index].1.push(record_type);
+                }
+            }
+        }
+
+        let mut addresses = HashMap::new();
+        for (source, record_types) in sources {
+            let resolved = self.resolve_source(source, &record_types).await;
+            for (record_type, address) in record_types.into_iter().zip(resolved) {
+                addresses.insert((source, record_type), address);
+            }
+        }
+        addresses
+    }
+
+    /// The addresses of the record types, in their order. Interfaces and neighbours are listed
+    /// once for both families.
+    async fn resolve_source(
+        &self,
+        source: Option<&AddressSource>,
+        record_types: &[RecordType],
+    ) -> Vec<Result<IpAddr, String>> {
+        match source {
+            Some(AddressSource::Interface(name)) => {
+                let listed = crate::interfaces::addresses(name)
+                    .map_err(|e| format!("Cannot list the network interfaces: {e}"));
+                select_all(&listed, record_types, |family| {
+                    format!("Interface {name} has no {family} address")
+                })
+            }
+            Some(AddressSource::Neighbour(mac)) => {
+                let listed = address_source::neighbour_addresses(mac).await;
+                select_all(&listed, record_types, |family| {
+                    format!("No {family} address of the neighbour {mac}")
+                })
+            }
+            _ => {
+                let mut addresses = Vec::new();
+                for record_type in record_types {
+                    addresses.push(self.resolve_address(source, *record_type).await);
+                }
+                addresses
+            }
+        }
+    }
+
+    /// The address of a source that is looked up per record type.
+    async fn resolve_address(
+        &self,
+        source: Option<&AddressSource>,
+        record_type: RecordType,
+    ) -> Result<IpAddr, String> {
+        match source {
+            None => resolve_with(self.resolver.as_ref(), record_type).await,
+            Some(AddressSource::Resolver(name)) => match self.resolvers.get(name) {
+                Some(resolver) => resolve_with(resolver.as_ref(), record_type).await,
+                None => Err(format!("Unknown resolver {name}")),
+            },
+            Some(AddressSource::Static { ipv4, ipv6 }) => match record_type {
+                RecordType::A => ipv4.map(IpAddr::V4),
+                RecordType::AAAA => ipv6.map(IpAddr::V6),
+            }
+            .ok_or_else(|| format!("No static {} address", family(record_type))),
+            Some(AddressSource::Interface(_) | AddressSource::Neighbour(_)) => {
+                unreachable!("Interfaces and neighbours are listed once for both families")
+            }
+        }
+    }
+
+    fn provider_concurrency(&self, registration: &Registration) -> usize {
+        [
+            self.options
+                .provider_concurrency
+                .get(registration.name)
+                .copied(),
+            registration.max_concurrency,
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(Semaphore::MAX_PERMITS)
+        .clamp(1, Semaphore::MAX_PERMITS)
+    }
+
+    fn create_provider(&self, service: &AccountService) -> Result<Arc<dyn Provider>, Error> {
+        let name = service.registration.name;
+        let auth_data = self.accounts.get(name, service.account.as_deref())?;
+        let http_client_factory = match self.accounts.binding(name, service.account.as_deref())? {
+            Some(binding) => self.http_client_factory.with_binding(binding.clone()),
+            None => self.http_client_factory.clone(),
+        };
+        Ok((service.registration.create)(auth_data, &http_client_factory)?.into())
+    }
+
+    /// Resolves the addresses of every address source once and updates every record whose
+    /// address changed since the last run, running up to [`Options::max_concurrency`] updates at
+    /// the same time. Failing records, including those whose address cannot be resolved or
+    /// whose provider cannot be created, are part of the report, while errors saving the state
+    /// abort the run. The state is only saved if no record failed.
+    pub async fn run_once(&self) -> Result<Report, Error> {
+        let addresses = self.resolve_addresses().await;
+        let default_address = |record_type| {
+            addresses
+                .get(&(None, record_type))
+                .and_then(|address| address.as_ref().ok().copied())
+        };
+        let mut report = Report {
+            ipv4: match default_address(RecordType::A) {
+                Some(IpAddr::V4(ip)) => Some(ip),
+                _ => None,
+            },
+            ipv6: match default_address(RecordType::AAAA) {
+                Some(IpAddr::V6(ip)) => Some(ip),
+                _ => None,
+            },
+            records: Vec::new(),
+        };
+
+        let previous = match (&self.state_store, self.options.force) {
+            (Some(state_store), false) => state_store.load(),
+            _ => None,
+        };
+        let mut current = IPs::default();
+        for ((source, _), address) in &addresses {
+            let Ok(address) = address else {
+                continue;
+            };
+            let ips = match source {
+                None => &mut current,
+                Some(source) => current.sources.entry(source.key()).or_default(),
+            };
+            match address {
+                IpAddr::V4(ip) => ips.ipv4 = Some(*ip),
+                IpAddr::V6(ip) => ips.ipv6 = Some(*ip),
+            }
+        }
+
+        let limit = Arc::new(Semaphore::new(
+            self.options
+                .max_concurrency
+                .clamp(1, Semaphore::MAX_PERMITS),
+        ));
+        let mut provider_limits: HashMap<&str, Arc<Semaphore>> = HashMap::new();
+        let mut tasks = JoinSet::new();
+        for service in &self.services {
+            // Kept as message, as it is reported for every record of the service.
+            let provider = self.create_provider(service).map_err(|error| match error {
+                Error::ConfigurationError(message) => message,
+                error => format!("{error:?}"),
+            });
+            let provider_limit = provider_limits
+                .entry(service.registration.name)
+                .or_insert_with(|| {
+                    Arc::new(Semaphore::new(
+                        self.provider_concurrency(&service.registration),
+                    ))
+                });
+
+            for (domain_index, domain) in service.domains.iter().enumerate() {
+                for (host_index, host) in domain.specifications.iter().enumerate() {
+                    for record_type in [RecordType::A, RecordType::AAAA] {
+                        if !has_record(host, record_type) {
+                            continue;
+                        }
+                        let source = host.address_source.as_ref();
+                        let address = &addresses[&(source, record_type)];
+                        let index = report.records.len();
+                        report.records.push(RecordReport {
+                            provider: service.registration.name,
+                            account: service.account.clone(),
+                            domain: domain.domain_name.clone(),
+                            host: host.host_name.clone(),
+                            record_type,
+                            ip: address.as_ref().ok().copied(),
+                            outcome: Outcome::Unchanged,
+                        });
+                        let ip = match address {
+                            Ok(ip) => *ip,
+                            Err(message) => {
+                                report.records[index].outcome =
+                                    Outcome::Failed(Error::ResolverError(message.clone()));
+                                continue;
+                            }
+                        };
+                        if previous.as_ref().is_some_and(|previous| {
+                            previous_ip(previous, source, record_type) == Some(ip)
+                        }) {
+                            continue;
+                        }
+
+                        let provider = match &provider {
+                            Ok(provider) => Arc::clone(provider),
+                            Err(message) => {
+                                report.records[index].outcome =
+                                    Outcome::Failed(Error::ConfigurationError(message.clone()));
+                                continue;
+                            }
+                        };
+
+                        let service = Arc::clone(service);
+                        let provider_limit = Arc::clone(provider_limit);
+                        let limit = Arc::clone(&limit);
+                        tasks.spawn(async move {
+                            // The provider's permit comes first, so waiting for it does not
+                            // hold back other providers.
+                            let _provider_permit = provider_limit.acquire_owned().await;
+                            let _permit = limit.acquire_owned().await;
+                            let domain = &service.domains[domain_index];
+                            let host = &domain.specifications[host_index];
+                            let result = update_record(provider.as_ref(), domain, host, ip).await;
+                            (index, result)
+                        });
+                    }
+                }
+            }
+        }
+
+        while let Some(result) = tasks.join_next().await {
+            let (index, result) = match result {
+                Ok(result) => result,
+                Err(error) => std::panic::resume_unwind(error.into_panic()),
+            };
+            report.records[index].outcome = match result {
+                Ok(()) => Outcome::Updated,
+                Err(error) => Outcome::Failed(error),
+            };
+        }
+
+        if report.is_success() {
+            if let Some(state_store) = &self.state_store {
+                state_store.save(&current)?;
+            }
+        }
+        Ok(report)
+    }
+}
+
+fn has_record(host: &HostSpecifications<AnySpecification>, record_type: RecordType) -> bool {
+    match record_type {
+        RecordType::A => host.ipv4.is_some(),
+        RecordType::AAAA => host.ipv6.is_some(),
+    }
+}
+
+fn previous_ip(
+    previous: &IPs,
+    source: Option<&AddressSource>,
+    record_type: RecordType,
+) -> Option<IpAddr> {
+    let previous = match source {
+        None => previous,
+        Some(source) => previous.sources.get(&source.key())?,
+    };
+    match record_type {
+        RecordType::A => previous.ipv4.map(IpAddr::V4),
+        RecordType::AAAA => previous.ipv6.map(IpAddr::V6),
+    }
+}
+
+async fn resolve_with(resolver: &dyn Resolver, record_type: RecordType) -> Result<IpAddr, String> {
+    match record_type {
+        RecordType::A => resolver.ipv4().await.map(IpAddr::V4),
+        RecordType::AAAA => resolver.ipv6().await.map(IpAddr::V6),
+    }
+    .ok_or_else(|| format!("no {} found", family(record_type)))
+}
+
+/// The address of every record type from the `listed` addresses of a source.
+fn select_all(
+    listed: &Result<Vec<IpAddr>, String>,
+    record_types: &[RecordType],
+    missing: impl Fn(&str) -> String,
+) -> Vec<Result<IpAddr, String>> {
+    record_types
+        .iter()
+        .map(|record_type| {
+            let listed = listed.as_ref().map_err(Clone::clone)?;
+            address_source::select(listed, *record_type)
+                .ok_or_else(|| missing(family(*record_type)))
+        })
+        .collect()
+}
+
+fn family(record_type: RecordType) -> &'static str {
+    match record_type {
+        RecordType::A => "IPv4",
+        RecordType::AAAA => "IPv6",
+    }
+}
+
+async fn update_record(
+    provider: &dyn Provider,
+    domain: &DomainSpecifications<AnySpecification>,
+    host: &HostSpecifications<AnySpecification>,
+    ip: IpAddr,
+) -> Result<(), Error> {
+    match (ip, &host.ipv4, &host.ipv6) {
+        (IpAddr::V4(ip), Some(specification), _) => {
+            provider
+                .update_ipv4_record(specification, &domain.domain_name, &host.host_name, ip)
+                .await
+        }
+        (IpAddr::V6(ip), _, Some(specification)) => {
+            provider
+                .update_ipv6_record(specification, &domain.domain_name, &host.host_name, ip)
+                .await
+        }
+        _ => unreachable!("Only records with a specification are planned"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+
+    use crate::updater::MemoryStateStore;
+    use crate::{RecordSpecificationV6Adapter, UpdateHandler};
+
+    use super::*;
+
+    /// What the stub providers of a test did, shared by the name in their authentication data,
+    /// as the providers are created by the updater.
+    #[derive(Default)]
+    struct Probe {
+        /// `<account> <host> <ip>` of every update.
+        updates: Mutex<Vec<String>>,
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+    }
+
+    static PROBES: Mutex<BTreeMap<String, Arc<Probe>>> = Mutex::new(BTreeMap::new());
+
+    fn probe(name: &str) -> Arc<Probe> {
+        Arc::clone(PROBES.lock().unwrap().entry(name.to_owned()).or_default())
+    }
+
+    impl Probe {
+        fn updates(&self) -> Vec<String> {
+            let mut updates = self.updates.lock().unwrap().clone();
+            updates.sort();
+            updates
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct StubAuthenticationData {
+        probe: String,
+        #[serde(default)]
+        account: String,
+    }
+
+    /// Takes a while to update a record, and fails to update hosts named `fail`.
+    struct StubHandler {
+        probe: Arc<Probe>,
+        account: String,
+    }
+
+    impl StubHandler {
+        async fn update(&self, host: &str, ip: IpAddr) -> Result<(), Error> {
+            let running = self.probe.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.probe.max_running.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.probe.running.fetch_sub(1, Ordering::SeqCst);
+            if host == "fail" {
+                return Err(Error::ConfigurationError("Stub failure".to_owned()));
+            }
+            let update = format!("{} {host} {ip}", self.account);
+            self.probe.updates.lock().unwrap().push(update);
+            Ok(())
+        }
+    }
+
+    impl UpdateHandler<StubAuthenticationData, Value, Value> for StubHandler {
+        fn new(
+            auth_data: &StubAuthenticationData,
+            _http_client_factory: &HttpClientFactory,
+        ) -> Result<Self, Error> {
+            Ok(StubHandler {
+                probe: probe(&auth_data.probe),
+                account: auth_data.account.clone(),
+            })
+        }
+
+        async fn update_ipv4_record(
+            &self,
+            _specification: &Value,
+            _domain: &str,
+            host: &str,
+            ip: Ipv4Addr,
+        ) -> Result<(), Error> {
+            self.update(host, IpAddr::V4(ip)).await
+        }
+
+        async fn update_ipv6_record(
+            &self,
+            _specification: &RecordSpecificationV6Adapter<Value>,
+            _domain: &str,
+            host: &str,
+            ip: Ipv6Addr,
+        ) -> Result<(), Error> {
+            self.update(host, IpAddr::V6(ip)).await
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::default();
+        registry.register(Registration::of_handler::<
+            StubHandler,
+            StubAuthenticationData,
+            Value,
+            Value,
+        >("Stub"));
+        registry
+    }
+
+    /// Lets a test look at the state after handing the store to the updater.
+    #[derive(Clone, Default)]
+    struct SharedStateStore(Arc<MemoryStateStore>);
+
+    impl StateStore for SharedStateStore {
+        fn load(&self) -> Option<IPs> {
+            self.0.load()
+        }
+
+        fn save(&self, ips: &IPs) -> Result<(), Error> {
+            self.0.save(ips)
+        }
+    }
+
+    struct StubResolver;
+
+    #[async_trait]
+    impl Resolver for StubResolver {
+        async fn ipv4(&self) -> Option<Ipv4Addr> {
+            Some(Ipv4Addr::new(192, 0, 2, 1))
+        }
+
+        async fn ipv6(&self) -> Option<Ipv6Addr> {
+            None
+        }
+    }
+
+    /// An `A` record for every host of `example.com`, with `domain` merged into the domain.
+    fn records(hosts: &[&str], domain: Value) -> DnsRecordList {
+        let specifications: Vec<Value> = hosts
+            .iter()
+            .map(|host| json!({"host_name": host, "ipv4": {}}))
+            .collect();
+        let mut domain_specifications =
+            json!({"domain_name": "example.com", "specifications": specifications});
+        for (key, value) in domain.as_object().into_iter().flatten() {
+            domain_specifications[key] = value.clone();
+        }
+        serde_json::from_value(json!([{ "Stub": [domain_specifications] }])).unwrap()
+    }
+
+    fn credentials(credentials: Value) -> AuthenticationDataList {
+        serde_json::from_value(credentials).unwrap()
+    }
+
+    fn updater(records: DnsRecordList, credentials: AuthenticationDataList) -> UpdaterBuilder {
+        Updater::builder()
+            .registry(registry())
+            .records(records)
+            .credentials(credentials)
+            .resolver(StubResolver)
+    }
+
+    fn build_error(builder: UpdaterBuilder) -> String {
+        match builder.build() {
+            Ok(_) => panic!("The updater was built"),
+            Err(Error::AuthenticationError(message) | Error::ConfigurationError(message)) => {
+                message
+            }
+            Err(error) => panic!("Unexpected error {error:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn updates_the_records_with_their_account() {
+        let probe = probe("accounts");
+        let updater = updater(
+            records(&["www"], json!({"account": "b"})),
+            credentials(json!([
+                {"Stub": {"probe": "accounts", "account": "unnamed"}},
+                {"name": "b", "Stub": {"probe": "accounts", "account": "b"}}
+            ])),
+        )
+        .build()
+        .unwrap();
+
+        let report = updater.run_once().await.unwrap();
+
+        assert!(report.is_success(), "{report:?}");
+        assert_eq!(report.records[0].account.as_deref(), Some("b"));
+        assert_eq!(probe.updates(), ["b www 192.0.2.1"]);
+    }
+
+    #[test]
+    fn rejects_duplicate_accounts() {
+        let message = build_error(updater(
+            records(&["www"], json!({})),
+            credentials(json!([
+                {"name": "a", "Stub": {"probe": "duplicate"}},
+                {"name": "a", "Stub": {"probe": "duplicate"}}
+            ])),
+        ));
+        assert_eq!(
+            message,
+            "Duplicate authentication data for account a of Stub."
+        );
+    }
+
+    #[test]
+    fn rejects_missing_accounts() {
+        let message = build_error(updater(
+            records(&["www"], json!({"account": "b"})),
+            credentials(json!([{"name": "a", "Stub": {"probe": "missing"}}])),
+        ));
+        assert_eq!(
+            message,
+            "No authentication data provided for account b of Stub."
+        );
+
+        let message = build_error(updater(records(&["www"], json!({})), Vec::new()));
+        assert_eq!(message, "No authentication data provided for Stub.");
+    }
+
+    #[test]
+    fn rejects_unknown_providers() {
+        let unknown_records = serde_json::from_value(json!([{ "Unknown": [] }])).unwrap();
+        let message = build_error(updater(unknown_records, Vec::new()));
+        assert_eq!(message, "Unknown provider Unknown");
+
+        let message = build_error(updater(Vec::new(), credentials(json!([{"Unknown": {}}]))));
+        assert_eq!(message, "Unknown provider Unknown");
+    }
+
+    /// Updates six records and returns the most updates that ran at the same time.
+    async fn max_running(probe_name: &str, registry: Registry, options: Options) -> usize {
+        let probe = probe(probe_name);
+        let hosts = ["a", "b", "c", "d", "e", "f"];
+        let updater = updater(
+            records(&hosts, json!({})),
+            credentials(json!([{ "Stub": {"probe": probe_name} }])),
+        )
+        .registry(registry)
+        .options(options)
+        .build()
+        .unwrap();
+
+        let report = updater.run_once().await.unwrap();
+
+        assert!(report.is_success(), "{report:?}");
+        assert_eq!(probe.updates().len(), hosts.len());
+        probe.max_running.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn limits_the_concurrent_updates() {
+        assert_eq!(
+            max_running("no-limit", registry(), Options::default()).await,
+            6
+        );
+        let options = Options {
+            max_concurrency: 2,
+            ..Options::default()
+        };
+        assert_eq!(max_running("global-limit", registry(), options).await, 2);
+    }
+
+    #[tokio::test]
+    async fn limits_the_concurrent_updates_of_a_provider() {
+        let options = Options {
+            provider_concurrency: HashMap::from([("Stub".to_owned(), 1)]),
+            ..Options::default()
+        };
+        assert_eq!(max_running("provider-limit", registry(), options).await, 1);
+
+        let mut registry = Registry::default();
+        registry.register(
+            Registration::of_handler::<StubHandler, StubAuthenticationData, Value, Value>("Stub")
+                .with_max_concurrency(1),
+        );
+        let options = Options {
+            provider_concurrency: HashMap::from([("Stub".to_owned(), 4)]),
+            ..Options::default()
+        };
+        assert_eq!(
+            max_running("registration-limit", registry, options).await,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn saves_the_state_only_if_every_record_succeeds() {
+        let probe = probe("state");
+        let state_store = SharedStateStore::default();
+        let run = |hosts: &[&str], force| {
+            let updater = updater(
+                records(hosts, json!({})),
+                credentials(json!([{"Stub": {"probe": "state"}}])),
+            )
+            .state_store(state_store.clone())
+            .options(Options {
+                force,
+                ..Options::default()
+            })
+            .build()
+            .unwrap();
+            async move { updater.run_once().await.unwrap() }
+        };
+
+        let report = run(&["www", "fail"], false).await;
+        assert!(!report.is_success());
+        assert!(matches!(report.records[0].outcome, Outcome::Updated));
+        assert!(state_store.load().is_none());
+
+        let report = run(&["www"], false).await;
+        assert!(matches!(report.records[0].outcome, Outcome::Updated));
+        let state = state_store.load().unwrap();
+        assert_eq!(state.ipv4, Some(Ipv4Addr::new(192, 0, 2, 1)));
+        assert_eq!(state.ipv6, None);
+
+        let report = run(&["www"], false).await;
+        assert!(matches!(report.records[0].outcome, Outcome::Unchanged));
+        assert_eq!(probe.updates().len(), 2);
+
+        let report = run(&["www"], true).await;
+        assert!(matches!(report.records[0].outcome, Outcome::Updated));
+        assert_eq!(probe.updates().len(), 3);
+    }
+
+    struct SecondResolver;
+
+    #[async_trait]
+    impl Resolver for SecondResolver {
+        async fn ipv4(&self) -> Option<Ipv4Addr> {
+            Some(Ipv4Addr::new(203, 0, 113, 1))
+        }
+
+        async fn ipv6(&self) -> Option<Ipv6Addr> {
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn updates_the_hosts_with_their_address_source() {
+        let probe = probe("sources");
+        let state_store = SharedStateStore::default();
+        let records = serde_json::from_value(json!([{ "Stub": [{
+            "domain_name": "example.com",
+            "specifications": [
+                {"host_name": "www", "ipv4": {}},
+                {"host_name": "nas", "ipv4": {}, "address_source": {"resolver": "second"}},
+                {
+                    "host_name": "printer",
+                    "ipv4": {},
+                    "address_source": {"static": {"ipv4": "198.51.100.7"}}
+                },
+                {"host_name": "camera", "ipv4": {}, "address_source": {"static": {}}}
+            ]
+        }]}]))
+        .unwrap();
+        let updater = updater(
+            records,
+            credentials(json!([{"Stub": {"probe": "sources"}}])),
+        )
+        .named_resolver("second", SecondResolver)
+        .state_store(state_store.clone())
+        .build()
+        .unwrap();
+
+        let report = updater.run_once().await.unwrap();
+
+        assert_eq!(
+            probe.updates(),
+            [
+                " nas 203.0.113.1",
+                " printer 198.51.100.7",
+                " www 192.0.2.1"
+            ]
+        );
+        let camera = &report.records[3];
+        assert_eq!(camera.ip, None);
+        assert!(
+            matches!(&camera.outcome, Outcome::Failed(Error::ResolverError(message)) if message == "No static IPv4 address"),
+            "{camera:?}"
+        );
+        assert!(state_store.load().is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_resolvers() {
+        let records = serde_json::from_value(json!([{ "Stub": [{
+            "domain_name": "example.com",
+            "specifications": [
+                {"host_name": "nas", "ipv4": {}, "address_source": {"resolver": "second"}}
+            ]
+        }]}]))
+        .unwrap();
+        let message = build_error(updater(
+            records,
+            credentials(json!([{"Stub": {"probe": "unknown-resolver"}}])),
+        ));
+        assert_eq!(message, "Unknown resolver second for nas in example.com");
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::RecordType;
+
+#[derive(Deserialize, Debug)]
+pub struct DomainRecord {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DomainRecordsPage {
+    pub records: Vec<DomainRecord>,
+    pub meta: Meta,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Meta {
+    pub links: Links,
+}
+
+/// `next` is an empty string on the last page.
+#[derive(Deserialize, Debug)]
+pub struct Links {
+    pub next: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct NewDomainRecord<'a> {
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub type_: RecordType,
+    pub data: String,
+    pub ttl: u32,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DomainRecordUpdate {
+    pub data: String,
+    pub ttl: u32,
+}
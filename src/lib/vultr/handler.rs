@@ -0,0 +1,152 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use reqwest::{header, Response};
+
+use crate::http_client::sensitive_header_value;
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::domain_record::{DomainRecordUpdate, DomainRecordsPage, NewDomainRecord};
+use super::{AuthenticationData, Error, RecordSpecification};
+
+const API_URL: &str = "https://api.vultr.com/v2";
+const PAGE_SIZE: u32 = 500;
+
+pub struct Handler {
+    http_client: reqwest::Client,
+}
+
+impl Handler {
+    async fn find_record_id(
+        &self,
+        domain: &str,
+        name: &str,
+        record_type: RecordType,
+    ) -> Result<Option<String>, Error> {
+        let mut cursor = String::new();
+        loop {
+            let response = self
+                .http_client
+                .get(format!("{API_URL}/domains/{domain}/records"))
+                .query(&[("per_page", PAGE_SIZE.to_string()), ("cursor", cursor)])
+                .send()
+                .await?;
+            let text = check_status(response).await?;
+            let page: DomainRecordsPage = serde_json::from_str(&text)?;
+            if let Some(record) = page
+                .records
+                .into_iter()
+                .find(|record| record.name == name && record.type_ == record_type.to_string())
+            {
+                return Ok(Some(record.id));
+            }
+            if page.meta.links.next.is_empty() {
+                return Ok(None);
+            }
+            cursor = page.meta.links.next;
+        }
+    }
+
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: &IpAddr,
+        record_type: RecordType,
+        ttl: u32,
+    ) -> Result<(), Error> {
+        let name = match name {
+            "@" => "",
+            _ => name,
+        };
+        let request = match self.find_record_id(domain, name, record_type).await? {
+            Some(id) => self
+                .http_client
+                .patch(format!("{API_URL}/domains/{domain}/records/{id}"))
+                .json(&DomainRecordUpdate {
+                    data: ip.to_string(),
+                    ttl,
+                }),
+            None => self
+                .http_client
+                .post(format!("{API_URL}/domains/{domain}/records"))
+                .json(&NewDomainRecord {
+                    name,
+                    type_: record_type,
+                    data: ip.to_string(),
+                    ttl,
+                }),
+        };
+        let response = request.send().await?;
+        check_status(response).await?;
+        Ok(())
+    }
+}
+
+async fn check_status(response: Response) -> Result<String, Error> {
+    let status = response.status();
+    let text = response.text().await?;
+    if status.is_success() {
+        Ok(text)
+    } else {
+        Err(Error::Request(format!(
+            "Request failed with Code {status:?}: {text}"
+        )))
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData { token }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let mut headers = header::HeaderMap::new();
+        let auth_value = sensitive_header_value(&format!("Bearer {token}"))?;
+        headers.insert(header::AUTHORIZATION, auth_value);
+
+        let client = http_client_factory
+            .builder()?
+            .default_headers(headers)
+            .build()?;
+
+        Ok(Handler {
+            http_client: client,
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            &IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
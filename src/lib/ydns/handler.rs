@@ -1,10 +1,9 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
-use std::panic::panic_any;
 
 use reqwest::StatusCode;
 
 use crate::update_handler::UpdateHandler;
-use crate::RecordSpecificationV6Adapter;
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter};
 
 use super::{AuthenticationData, SpecificationV4, SpecificationV6};
 
@@ -45,19 +44,17 @@ impl Handler {
 }
 
 impl UpdateHandler<AuthenticationData, SpecificationV4, SpecificationV6> for Handler {
-    fn new(AuthenticationData { username, secret }: &AuthenticationData) -> Self {
-        let client = match reqwest::Client::builder().build() {
-            Err(e) => panic_any(e),
-            Ok(c) => c,
-        };
+    fn new(
+        AuthenticationData { username, secret }: &AuthenticationData,
+        http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        let client = http_client_factory.client()?;
 
-        let handler = Handler {
+        Ok(Handler {
             http_client: client,
             user: username.clone(),
             password: secret.clone(),
-        };
-
-        handler
+        })
     }
 
     async fn update_ipv4_record(
@@ -76,21 +73,11 @@ impl UpdateHandler<AuthenticationData, SpecificationV4, SpecificationV6> for Han
         specification: &RecordSpecificationV6Adapter<SpecificationV6>,
         domain: &str,
         host: &str,
-        mut ip:Ipv6Addr,
+        ip: Ipv6Addr,
     ) -> Result<(), crate::Error> {
-        if let Some(interface_id) = specification.custom_interface_id{
-            replace_interface_id(&mut ip, interface_id);
-        }
+        let ip = specification.apply_custom_interface_id(ip);
         self.update_ip_address(domain, host, &IpAddr::V6(ip))
             .await?;
         Ok(())
     }
 }
-
-fn replace_interface_id(ip: &mut Ipv6Addr, interface_id: Ipv6Addr) {
-    let interface_id = interface_id.segments();
-    let suffix = interface_id.rsplit_array_ref::<4>().1;
-    let mut ipv6_segments = ip.segments();
-    ipv6_segments[4..].copy_from_slice(suffix);
-    *ip = Ipv6Addr::from(ipv6_segments);
-}
@@ -8,3 +8,12 @@ pub use error::Error;
 pub use handler::Handler;
 pub use record_specification::SpecificationV4;
 pub use record_specification::SpecificationV6;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(crate::Registration::of_handler::<
+        Handler,
+        AuthenticationData,
+        SpecificationV4,
+        SpecificationV6,
+    >("YDns"));
+}
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthenticationData {
+    /// The zone file of every domain, keyed by the domain.
+    pub zone_files: HashMap<String, PathBuf>,
+    /// Program and arguments run after a zone file was rewritten, e.g.
+    /// `["rndc", "reload"]`.
+    pub reload_command: Option<Vec<String>>,
+}
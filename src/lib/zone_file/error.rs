@@ -0,0 +1,38 @@
+pub enum Error {
+    Io(std::io::Error),
+    MissingZoneFile(String),
+    /// The zone has no SOA record with a parsable serial.
+    InvalidSoa(String),
+    Reload(String),
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => e.fmt(f),
+            Error::MissingZoneFile(e) => e.fmt(f),
+            Error::InvalidSoa(e) => e.fmt(f),
+            Error::Reload(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> crate::Error {
+        crate::Error::Provider(Box::new(err))
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::PathBuf;
+
+use tokio::process::Command;
+
+use crate::{HttpClientFactory, RecordSpecificationV6Adapter, RecordType, UpdateHandler};
+
+use super::zone::Zone;
+use super::{AuthenticationData, Error, RecordSpecification};
+
+pub struct Handler {
+    zone_files: HashMap<String, PathBuf>,
+    reload_command: Option<Vec<String>>,
+}
+
+impl Handler {
+    async fn update_ip_address(
+        &self,
+        domain: &str,
+        name: &str,
+        ip: IpAddr,
+        record_type: RecordType,
+        ttl: Option<u32>,
+    ) -> Result<(), Error> {
+        let path = self.zone_files.get(domain).ok_or_else(|| {
+            Error::MissingZoneFile(format!("No zone file provided for {domain}."))
+        })?;
+        let origin = format!("{domain}.");
+        let fqdn = match name {
+            "@" => origin.clone(),
+            _ => format!("{name}.{origin}"),
+        };
+
+        let mut zone = Zone::parse(&tokio::fs::read_to_string(path).await?);
+        if !zone.set_record(&origin, &fqdn, record_type, ip, ttl) {
+            return Ok(());
+        }
+        zone.bump_serial()?;
+        crate::files::write_atomically(path, &zone.render()).await?;
+        self.reload().await
+    }
+
+    async fn reload(&self) -> Result<(), Error> {
+        let Some((program, args)) = self
+            .reload_command
+            .as_ref()
+            .and_then(|command| command.split_first())
+        else {
+            return Ok(());
+        };
+        let output = Command::new(program).args(args).output().await?;
+        if !output.status.success() {
+            return Err(Error::Reload(format!(
+                "{program} failed with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl UpdateHandler<AuthenticationData, RecordSpecification, RecordSpecification> for Handler {
+    fn new(
+        AuthenticationData {
+            zone_files,
+            reload_command,
+        }: &AuthenticationData,
+        _http_client_factory: &HttpClientFactory,
+    ) -> Result<Self, crate::Error> {
+        Ok(Handler {
+            zone_files: zone_files.clone(),
+            reload_command: reload_command.clone(),
+        })
+    }
+
+    async fn update_ipv4_record(
+        &self,
+        specification: &RecordSpecification,
+        domain: &str,
+        host: &str,
+        ip: Ipv4Addr,
+    ) -> Result<(), crate::Error> {
+        self.update_ip_address(
+            domain,
+            host,
+            IpAddr::V4(ip),
+            RecordType::A,
+            specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_ipv6_record(
+        &self,
+        specification: &RecordSpecificationV6Adapter<RecordSpecification>,
+        domain: &str,
+        host: &str,
+        ip: Ipv6Addr,
+    ) -> Result<(), crate::Error> {
+        let ip = specification.apply_custom_interface_id(ip);
+        self.update_ip_address(
+            domain,
+            host,
+            IpAddr::V6(ip),
+            RecordType::AAAA,
+            specification.record_specification.ttl,
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+mod authentication_data;
+mod error;
+mod handler;
+mod record_specification;
+mod zone;
+
+pub use authentication_data::AuthenticationData;
+pub use error::Error;
+pub use handler::Handler;
+pub use record_specification::RecordSpecification;
+
+pub fn register(registry: &mut crate::Registry) {
+    registry.register(
+        crate::Registration::of_handler::<
+            Handler,
+            AuthenticationData,
+            RecordSpecification,
+            RecordSpecification,
+        >("ZoneFile")
+        .with_max_concurrency(1),
+    );
+}
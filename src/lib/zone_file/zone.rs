@@ -0,0 +1,430 @@
+use std::net::IpAddr;
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::RecordType;
+
+use super::Error;
+
+const CLASSES: [&str; 4] = ["IN", "CH", "HS", "CS"];
+
+/// A BIND zone file, edited line by line so that formatting and comments survive.
+pub struct Zone {
+    lines: Vec<String>,
+}
+
+impl Zone {
+    pub fn parse(content: &str) -> Zone {
+        Zone {
+            lines: content.lines().map(str::to_owned).collect(),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        self.lines.join("\n") + "\n"
+    }
+
+    /// Points every `record_type` record of `fqdn` to `ip`, or appends a record if there is
+    /// none. `origin` is the origin the file starts with, `fqdn` has to be absolute. Returns
+    /// whether the zone changed.
+    pub fn set_record(
+        &mut self,
+        origin: &str,
+        fqdn: &str,
+        record_type: RecordType,
+        ip: IpAddr,
+        ttl: Option<u32>,
+    ) -> bool {
+        let record_type = record_type.to_string();
+        let ip = ip.to_string();
+        let ttl = ttl.map(|ttl| ttl.to_string());
+        let mut origin = origin.to_owned();
+        let mut owner: Option<String> = None;
+        let mut depth = 0usize;
+        let mut found = false;
+        let mut changed = false;
+
+        for line in &mut self.lines {
+            let ranges = fields(line);
+            let tokens: Vec<&str> = ranges.iter().map(|range| &line[range.clone()]).collect();
+            let opened = tokens.iter().filter(|token| **token == "(").count();
+            let closed = tokens.iter().filter(|token| **token == ")").count();
+            // Continuation lines of a multi-line record, such as the SOA, are no records.
+            let continuation = depth > 0;
+            depth = (depth + opened).saturating_sub(closed);
+            if continuation || tokens.is_empty() {
+                continue;
+            }
+            if tokens[0].starts_with('$') {
+                if tokens[0].eq_ignore_ascii_case("$ORIGIN") && tokens.len() > 1 {
+                    origin = absolute_name(tokens[1], &origin);
+                }
+                continue;
+            }
+
+            let mut index = 0;
+            if !line.starts_with(char::is_whitespace) {
+                owner = Some(absolute_name(tokens[0], &origin));
+                index = 1;
+            }
+            let mut ttl_index = None;
+            while let Some(token) = tokens.get(index) {
+                if token.starts_with(|c: char| c.is_ascii_digit()) {
+                    ttl_index = Some(index);
+                } else if !CLASSES
+                    .iter()
+                    .any(|class| token.eq_ignore_ascii_case(class))
+                {
+                    break;
+                }
+                index += 1;
+            }
+            let matches = owner
+                .as_deref()
+                .is_some_and(|owner| owner.eq_ignore_ascii_case(fqdn))
+                && tokens
+                    .get(index)
+                    .is_some_and(|token| token.eq_ignore_ascii_case(&record_type))
+                && tokens.len() == index + 2;
+            if !matches {
+                continue;
+            }
+            found = true;
+
+            // Replaced from right to left, so the ranges in front stay valid.
+            let mut edited = line.clone();
+            if tokens[index + 1].parse::<IpAddr>().ok() != ip.parse().ok() {
+                edited.replace_range(ranges[index + 1].clone(), &ip);
+            }
+            if let Some(ttl) = &ttl {
+                match ttl_index {
+                    Some(ttl_index) if tokens[ttl_index] != ttl => {
+                        edited.replace_range(ranges[ttl_index].clone(), ttl)
+                    }
+                    Some(_) => {}
+                    None => edited.insert_str(ranges[index].start, &format!("{ttl} ")),
+                }
+            }
+            if edited != *line {
+                *line = edited;
+                changed = true;
+            }
+        }
+
+        if !found {
+            let ttl = ttl.map(|ttl| format!("{ttl} ")).unwrap_or_default();
+            self.lines
+                .push(format!("{fqdn} {ttl}IN {record_type} {ip}"));
+            changed = true;
+        }
+        changed
+    }
+
+    /// Increments the serial of the SOA record. Serials in the common `YYYYMMDDnn` form move on
+    /// to the current date if they lag behind.
+    pub fn bump_serial(&mut self) -> Result<(), Error> {
+        let mut after_soa = None;
+        for (line_index, line) in self.lines.iter_mut().enumerate() {
+            for range in fields(line) {
+                let token = &line[range.clone()];
+                match after_soa {
+                    None if token.eq_ignore_ascii_case("SOA") => after_soa = Some(0),
+                    None => {}
+                    Some(_) if token == "(" || token == ")" => {}
+                    // The serial follows the primary name server and the mailbox.
+                    Some(2) => {
+                        let serial: u32 = token.parse().map_err(|_| {
+                            Error::InvalidSoa(format!(
+                                "Invalid serial {token} in line {}",
+                                line_index + 1
+                            ))
+                        })?;
+                        line.replace_range(range, &next_serial(serial, today()).to_string());
+                        return Ok(());
+                    }
+                    Some(fields) => after_soa = Some(fields + 1),
+                }
+            }
+        }
+        Err(Error::InvalidSoa("No SOA record found".to_owned()))
+    }
+}
+
+/// Byte ranges of the fields of a zone file line, leaving out comments. Parentheses are fields
+/// of their own.
+fn fields(line: &str) -> Vec<Range<usize>> {
+    let mut fields = Vec::new();
+    let mut start = None;
+    let mut quoted = false;
+    let mut end = line.len();
+    for (i, c) in line.char_indices() {
+        if quoted {
+            quoted = c != '"';
+            continue;
+        }
+        match c {
+            ';' => {
+                end = i;
+                break;
+            }
+            '(' | ')' => {
+                if let Some(start) = start.take() {
+                    fields.push(start..i);
+                }
+                fields.push(i..i + 1);
+            }
+            c if c.is_whitespace() => {
+                if let Some(start) = start.take() {
+                    fields.push(start..i);
+                }
+            }
+            c => {
+                quoted = c == '"';
+                start.get_or_insert(i);
+            }
+        }
+    }
+    if let Some(start) = start {
+        fields.push(start..end);
+    }
+    fields
+}
+
+fn absolute_name(name: &str, origin: &str) -> String {
+    match name {
+        "@" => origin.to_owned(),
+        _ if name.ends_with('.') => name.to_owned(),
+        _ => format!("{name}.{origin}"),
+    }
+}
+
+/// `today` is the date as `YYYYMMDD`.
+fn next_serial(serial: u32, today: u64) -> u32 {
+    let dated = today * 100;
+    if serial >= 1_970_010_100 && u64::from(serial) < dated && dated <= u64::from(u32::MAX) {
+        dated as u32
+    } else {
+        serial.wrapping_add(1)
+    }
+}
+
+/// The current UTC date as `YYYYMMDD`.
+fn today() -> u64 {
+    date(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs() / 86_400) as i64,
+    )
+}
+
+/// Converts days since the epoch to a civil date as `YYYYMMDD`, see
+/// https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn date(days: i64) -> u64 {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year * 10_000 + month * 100 + day) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ZONE: &str = "\
+$ORIGIN example.com.
+$TTL 3600
+@\tIN SOA ns1.example.com. hostmaster.example.com. (
+\t\t1 ; serial
+\t\t7200 3600 1209600 3600 )
+\tIN NS ns1.example.com.
+www     300 IN A 192.0.2.1 ; web server
+mail    IN  AAAA 2001:db8::1
+\tIN  A 192.0.2.25
+$ORIGIN sub.example.com.
+host IN A 192.0.2.3
+txt IN TXT \"; not a comment\"
+";
+
+    fn set(zone: &mut Zone, fqdn: &str, record_type: RecordType, ip: &str) -> bool {
+        zone.set_record("example.com.", fqdn, record_type, ip.parse().unwrap(), None)
+    }
+
+    #[test]
+    fn renders_unchanged_zone_as_parsed() {
+        assert_eq!(Zone::parse(ZONE).render(), ZONE);
+    }
+
+    #[test]
+    fn replaces_address_keeping_formatting() {
+        let mut zone = Zone::parse(ZONE);
+        assert!(set(
+            &mut zone,
+            "www.example.com.",
+            RecordType::A,
+            "198.51.100.1"
+        ));
+        assert_eq!(
+            zone.render(),
+            ZONE.replace(
+                "www     300 IN A 192.0.2.1 ; web server",
+                "www     300 IN A 198.51.100.1 ; web server"
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_zone_with_current_address() {
+        let mut zone = Zone::parse(ZONE);
+        assert!(!set(
+            &mut zone,
+            "www.example.com.",
+            RecordType::A,
+            "192.0.2.1"
+        ));
+        assert!(!set(
+            &mut zone,
+            "mail.example.com.",
+            RecordType::AAAA,
+            "2001:db8:0::1"
+        ));
+        assert_eq!(zone.render(), ZONE);
+    }
+
+    #[test]
+    fn applies_origin_and_previous_owner() {
+        let mut zone = Zone::parse(ZONE);
+        assert!(set(
+            &mut zone,
+            "mail.example.com.",
+            RecordType::A,
+            "198.51.100.25"
+        ));
+        assert!(set(
+            &mut zone,
+            "host.sub.example.com.",
+            RecordType::A,
+            "198.51.100.3"
+        ));
+        let rendered = zone.render();
+        assert!(rendered.contains("\tIN  A 198.51.100.25\n"));
+        assert!(rendered.contains("host IN A 198.51.100.3\n"));
+        assert!(rendered.contains("mail    IN  AAAA 2001:db8::1\n"));
+    }
+
+    #[test]
+    fn sets_ttl() {
+        let mut zone = Zone::parse(ZONE);
+        let ip = "192.0.2.1".parse().unwrap();
+        assert!(zone.set_record(
+            "example.com.",
+            "www.example.com.",
+            RecordType::A,
+            ip,
+            Some(60)
+        ));
+        let ip = "2001:db8::1".parse().unwrap();
+        assert!(zone.set_record(
+            "example.com.",
+            "mail.example.com.",
+            RecordType::AAAA,
+            ip,
+            Some(60)
+        ));
+        let rendered = zone.render();
+        assert!(rendered.contains("www     60 IN A 192.0.2.1 ; web server\n"));
+        assert!(rendered.contains("mail    IN  60 AAAA 2001:db8::1\n"));
+    }
+
+    #[test]
+    fn appends_missing_record() {
+        let mut zone = Zone::parse(ZONE);
+        assert!(set(
+            &mut zone,
+            "new.example.com.",
+            RecordType::AAAA,
+            "2001:db8::2"
+        ));
+        assert_eq!(
+            zone.render(),
+            format!("{ZONE}new.example.com. IN AAAA 2001:db8::2\n")
+        );
+    }
+
+    #[test]
+    fn ignores_names_in_record_data() {
+        let mut zone = Zone::parse(ZONE);
+        assert!(set(
+            &mut zone,
+            "ns1.example.com.",
+            RecordType::A,
+            "192.0.2.53"
+        ));
+        assert!(zone.render().starts_with(ZONE));
+    }
+
+    #[test]
+    fn splits_fields_without_comments() {
+        let line = "txt IN TXT \"; quoted\" ( 1 ) ; comment";
+        let fields: Vec<&str> = fields(line).into_iter().map(|range| &line[range]).collect();
+        assert_eq!(fields, ["txt", "IN", "TXT", "\"; quoted\"", "(", "1", ")"]);
+    }
+
+    #[test]
+    fn bumps_serial_in_multi_line_soa() {
+        let mut zone = Zone::parse(ZONE);
+        zone.bump_serial().unwrap();
+        assert_eq!(
+            zone.render(),
+            ZONE.replace("\t\t1 ; serial", "\t\t2 ; serial")
+        );
+    }
+
+    #[test]
+    fn bumps_serial_in_single_line_soa() {
+        let mut zone =
+            Zone::parse("@ 3600 IN SOA ns1 hostmaster 2000010101 7200 3600 1209600 3600\n");
+        zone.bump_serial().unwrap();
+        assert_eq!(
+            zone.render(),
+            format!(
+                "@ 3600 IN SOA ns1 hostmaster {} 7200 3600 1209600 3600\n",
+                today() * 100
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_zone_without_soa() {
+        let mut zone = Zone::parse("www IN A 192.0.2.1\n");
+        assert!(zone.bump_serial().is_err());
+        let mut zone = Zone::parse("@ IN SOA ns1 hostmaster ( serial 7200 )\n");
+        assert!(zone.bump_serial().is_err());
+    }
+
+    #[test]
+    fn moves_dated_serials_to_today() {
+        assert_eq!(next_serial(2024010103, 20240229), 2024022900);
+        assert_eq!(next_serial(2024022900, 20240229), 2024022901);
+        assert_eq!(next_serial(2024030100, 20240229), 2024030101);
+        assert_eq!(next_serial(41, 20240229), 42);
+        assert_eq!(next_serial(u32::MAX, 20240229), 0);
+    }
+
+    #[test]
+    fn converts_days_to_dates() {
+        assert_eq!(date(0), 19700101);
+        assert_eq!(date(10957), 20000101);
+        assert_eq!(date(19782), 20240229);
+    }
+}